@@ -13,6 +13,7 @@
     - Script
   - primary functions
     - general
+    - source changes
     - argument applicators
   - utility functions
 
@@ -28,6 +29,12 @@
   - argument applicators ('version', 'help')
   - utility functions
 
+  ERROR
+  - imports
+  - data structures
+    - Error + components
+    - Location
+
   TEST
   - imports
   - test cases
@@ -39,84 +46,256 @@
 
 /* - imports */
 
-use std::io::{self, Read, Write};
-use std::thread;
-use std::sync::mpsc;
-use std::time::{Duration, SystemTime};
+use std::io::{self, Read, IsTerminal};
+use std::time::SystemTime;
 use std::env;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::fs;
 use std::process;
 use std::collections::HashMap;
 
+use regex::Regex;
+
 use crate::output::{
   Output,
   OutputText,
-  OutputFile
+  OutputFile,
+  OutputFileInit,
+  OutputFileCmdPart,
+  OutputManifest
 };
 use crate::config::{
   Config,
   ConfigDefaults,
   ConfigSettings,
   ConfigMessages,
-  ConfigReceipts,
   ConfigSetting,
   ConfigReceiptVal
 };
+use crate::error::{
+  Error,
+  ErrorKind,
+  Location
+};
 
 /* - configuration */
 
-static DEFAULTS: [(&str, &str); 10] = [
+static DEFAULTS: [(&str, &str); 14] = [
   ("path_src",     "src.txt"     ), /* source file path (incl. output stem) */
   ("path_dir",     "scripts"     ), /* output directory name */
-  ("path_tmp_dir",".aliesce_tmp" ), /* source backup directory name, present during write to source */
+  ("path_bak_dir",".aliesce_bak" ), /* source backup, lock and output manifest directory name, alongside source */
   ("tag_head",     "###"         ),
   ("tag_tail",     "#"           ),
   ("sig_stop",     "!"           ),
   ("plc_path_dir", ">"           ),
   ("plc_path_all", ">{}<"        ), /* '{}' is optional script no. position */
   ("cmd_prog",     "bash"        ),
-  ("cmd_flag",     "-c"          )
+  ("cmd_flag",     "-c"          ),
+  ("bak_count",    "5"           ), /* no. of source backups kept */
+  ("sig_shell",    "@"           ), /* shell for composite command, as tag line prefix */
+  ("stdin_mode",   "auto"        ), /* handling of stdin - 'auto', 'paths' or 'pass' */
+  ("path_base",    "auto"        )  /* base of relative output paths - 'auto', 'source' or 'cwd' */
+];
+
+/* commands run for output extension where none in tag line */
+static COMMANDS: [(&str, &str); 14] = [
+  ("py",   "python3"),
+  ("rb",   "ruby"   ),
+  ("exs",  "elixir" ),
+  ("js",   "node"   ),
+  ("mjs",  "node"   ),
+  ("sh",   "sh"     ),
+  ("bash", "bash"   ),
+  ("zsh",  "zsh"    ),
+  ("fish", "fish"   ),
+  ("pl",   "perl"   ),
+  ("php",  "php"    ),
+  ("lua",  "lua"    ),
+  ("r",    "Rscript"),
+  ("jl",   "julia"  )
 ];
 
+/* configuration file paths, for defaults overridden by key, user within config dir, project alongside source */
+static PATH_CFG_USER:    &str = "aliesce/aliesce.conf";
+static PATH_CFG_PROJECT: &str = ".aliesce.conf";
+
+/* output manifest filename, for hash of content last saved per output path, within source backup directory, or for source on stdin output directory */
+static PATH_MANIFEST: &str = "manifest";
+
 fn settings_new(defaults: &ConfigDefaults) -> ConfigSettings {
 
-  let path_src = defaults.expect("path_src");
-  let path_dir = defaults.expect("path_dir");
+  let path_src     = defaults.expect("path_src");
+  let path_dir     = defaults.expect("path_dir");
+  let path_bak_dir = defaults.expect("path_bak_dir");
+  let bak_count    = defaults.expect("bak_count");
+  let tag_head     = defaults.expect("tag_head");
+  let tag_tail     = defaults.expect("tag_tail");
+  let sig_stop     = defaults.expect("sig_stop");
+  let plc_path_dir = defaults.expect("plc_path_dir");
+  let plc_path_all = defaults.expect("plc_path_all");
+  let cmd_prog     = defaults.expect("cmd_prog");
+  let cmd_flag     = defaults.expect("cmd_flag");
+  let sig_shell    = defaults.expect("sig_shell");
+  let stdin_mode   = defaults.expect("stdin_mode");
+  let path_base    = defaults.expect("path_base");
+
+  let plc_path_all_empty = plc_path_all.replace("{}", "");
 
+  /* settings called in order listed, so those setting values before those applying them */
   Vec::from([
     ConfigSetting::new(
       "list", "l", &[],
       &format!("print for each script in SOURCE (def. '{path_src}') its number and tag line content, without saving or running"),
       &setting_list_apply
     ),
+    ConfigSetting::new(
+      "dry-run", "n", &[],
+      "print for each script the save path and the command to be run, incl. any shell, without saving or running",
+      &setting_dry_run_apply
+    ),
+    ConfigSetting::new(
+      "config", "g", &[],
+      "print each default, option received and value derived, with its origin, i.e. compiled default, configuration file, command line or source preface, then exit",
+      &setting_config_apply
+    ).cli_only(),
     ConfigSetting::new(
       "only", "o", &["SUBSET"],
-      "include only the scripts the numbers of which appear in SUBSET, comma-separated and/or as ranges, e.g. -o 1,3-5",
+      "include only the scripts the numbers of which appear in SUBSET, comma-separated and/or as ranges, e.g. -o 1,3-5, repeatable",
       &setting_only_apply
-    ),
+    ).completing(&["scripts"]).repeatable(),
     ConfigSetting::new(
       "dest", "d", &["DIRNAME"],
       &format!("set the default output dirname ('{path_dir}') to DIRNAME"),
       &setting_dest_apply
+    ).completing(&["dir"]),
+    ConfigSetting::new(
+      "stem", "f", &["STEM"],
+      &format!("set the output stem used where none in tag line to STEM, in place of the stem of SOURCE, or of '{path_src}' where SOURCE is '-', i.e. read from stdin"),
+      &setting_stem_apply
+    ),
+    ConfigSetting::new(
+      "base", "B", &["BASE"],
+      &format!("set the base of relative output paths, whether the output dirname or tag line paths, to BASE, i.e. 'source' for the directory of SOURCE, 'cwd' for the current directory, or 'auto' for the directory of SOURCE only where begun with a hashbang line (def. '{path_base}')"),
+      &setting_base_apply
+    ).completing(&["base"]),
+    ConfigSetting::new(
+      "head", "H", &["STR"],
+      &format!("set the tag head ('{tag_head}') to STR"),
+      &setting_syntax_apply
+    ),
+    ConfigSetting::new(
+      "tail", "T", &["STR"],
+      &format!("set the tag tail ('{tag_tail}') to STR"),
+      &setting_syntax_apply
+    ),
+    ConfigSetting::new(
+      "stop", "S", &["STR"],
+      &format!("set the signal ('{sig_stop}') to STR"),
+      &setting_syntax_apply
     ),
+    ConfigSetting::new(
+      "plc-dir", "D", &["STR"],
+      &format!("set the output directory placeholder ('{plc_path_dir}') to STR"),
+      &setting_syntax_apply
+    ),
+    ConfigSetting::new(
+      "plc-all", "A", &["STR"],
+      &format!("set the output path placeholder ('{plc_path_all}') to STR, with '{{}}' as the position of any script no."),
+      &setting_plc_all_apply
+    ),
+    ConfigSetting::new(
+      "shell", "s", &["SHELL"],
+      &format!("set the shell to which a command incl. the '{plc_path_all_empty}' placeholder is passed ('{cmd_prog} {cmd_flag}') to SHELL, i.e. program and any flags, def. '{cmd_flag}'"),
+      &setting_shell_apply
+    ),
+    ConfigSetting::new(
+      "shell-sig", "y", &["STR"],
+      &format!("set the signal ('{sig_shell}') before a shell begun in tag line data to STR, e.g. where an output path begins with it"),
+      &setting_syntax_apply
+    ),
+    ConfigSetting::new(
+      "cmd", "C", &["EXT", "COMMAND"],
+      "set the command run for the output extension EXT where none in tag line to COMMAND, or to none if COMMAND is empty, repeatable",
+      &setting_cmd_apply
+    ).repeatable(),
+    ConfigSetting::new(
+      "keep", "k", &["COUNT"],
+      &format!("set the no. of backups of SOURCE kept in the directory '{path_bak_dir}' alongside on each change to COUNT (def. {bak_count}), or 0 for none"),
+      &setting_keep_apply
+    ),
+    ConfigSetting::new(
+      "force", "F", &[],
+      "save each script even where the output file has changed since last saved, in place of exiting with an error",
+      &setting_force_apply
+    ),
+    ConfigSetting::new(
+      "profile", "P", &["NAME"],
+      "apply also the options listed in the source preface below the line '[NAME]', after those above any such line, so taking precedence",
+      &setting_profile_apply
+    ).cli_only(),
+    ConfigSetting::new(
+      "stdin", "I", &["MODE"],
+      &format!("set the handling of stdin to MODE, i.e. 'paths' to read paths of scripts to append, 'pass' to leave stdin to the scripts run, or 'auto' to read paths only where stdin is a pipe or file (def. '{stdin_mode}')"),
+      &setting_stdin_apply
+    ).completing(&["mode"]).cli_only(),
     ConfigSetting::new(
       "init", "i", &[],
       &format!("create the source file SOURCE (def. '{path_src}') then exit"),
       &setting_init_apply
-    ),
+    ).cli_only(),
     ConfigSetting::new(
       "push", "p", &["LINE", "PATH"],
       &format!("append to SOURCE (def. '{path_src}') LINE, adding the tag head if none, followed by the content at PATH then exit"),
       &setting_push_apply
-    ),
+    ).completing(&["none", "file"]).cli_only(),
+    ConfigSetting::new(
+      "push-stdin", "a", &["LINE"],
+      &format!("append to SOURCE (def. '{path_src}') LINE, adding the tag head if none, followed by the content read from stdin then exit"),
+      &setting_push_stdin_apply
+    ).cli_only(),
     ConfigSetting::new(
       "edit", "e", &["N", "LINE"],
       "update the tag line for script number N to LINE, adding the tag head if none, then exit",
       &setting_edit_apply
-    ),
+    ).completing(&["scripts", "none"]).cli_only(),
+    ConfigSetting::new(
+      "label", "b", &["N", "TEXT"],
+      "update the label for script number N to TEXT, or remove the label if TEXT is empty, then exit, refusing TEXT including the tag head, the tag tail or a line break",
+      &setting_label_apply
+    ).completing(&["scripts", "none"]).cli_only(),
+    ConfigSetting::new(
+      "retag", "r", &["PATTERN", "REPLACEMENT"],
+      "replace each match for the regular expression PATTERN in tag line data with REPLACEMENT, for the scripts in any --only subset or else all, show the changes then exit, refusing data including a line break or a tag tail changing the label - with --list, show without updating",
+      &setting_retag_apply
+    ).cli_only(),
+    ConfigSetting::new(
+      "extract", "x", &["SUBSET", "DEST"],
+      &format!("move the scripts the numbers of which appear in SUBSET, as for --only, to the end of the source file DEST, creating DEST with any shebang and preface of SOURCE (def. '{path_src}') if none, then exit"),
+      &setting_extract_apply
+    ).completing(&["scripts", "file"]).cli_only(),
+    ConfigSetting::new(
+      "copy", "c", &["SUBSET", "DEST"],
+      "copy the scripts the numbers of which appear in SUBSET to DEST, as for --extract, then exit",
+      &setting_copy_apply
+    ).completing(&["scripts", "file"]).cli_only(),
+    ConfigSetting::new(
+      "merge", "m", &["OTHER"],
+      &format!("append to SOURCE (def. '{path_src}') all scripts in the source file OTHER, without its preface, then exit"),
+      &setting_merge_apply
+    ).completing(&["file"]).cli_only(),
+    ConfigSetting::new(
+      "undo", "u", &[],
+      &format!("restore SOURCE (def. '{path_src}') from the most recent backup, removing that backup, then exit"),
+      &setting_undo_apply
+    ).cli_only(),
+    ConfigSetting::new(
+      "completions", "w", &["KIND"],
+      &format!("print the completion script for the shell KIND, i.e. 'bash', 'zsh' or 'fish', or for KIND 'scripts' the number and label or else tag line data of each script in SOURCE (def. '{path_src}'), then exit"),
+      &setting_completions_apply
+    ).completing(&["kind"]).cli_only(),
     ConfigSetting::new_version(),
-    ConfigSetting::new_help()
+    ConfigSetting::new_help(),
+    ConfigSetting::new_man()
   ])
 }
 
@@ -135,6 +314,12 @@ fn messages_new(defaults: &ConfigDefaults) -> ConfigMessages<'static> {
   let plc_path_all_empty = plc_path_all.replace("{}", "");
   let plc_path_all_n     = plc_path_all.replace("{}", "n");
 
+  let cmd_example = defaults.commands
+    .get_key_value("py")
+    .or(defaults.commands.iter().min())
+    .map(|(ext, cmd)| format!(", e.g. '{cmd}' for '{ext}'"))
+    .unwrap_or_default();
+
   let repository = [
     (
       "file",
@@ -146,30 +331,45 @@ fn messages_new(defaults: &ConfigDefaults) -> ConfigMessages<'static> {
     ),
     (
       "main",
-      format!("Each script is saved with the default output directory ('{path_dir}'), source file stem and OUTPUT EXTENSION, or a PATH overriding stem and/or directory, then the COMMAND is run with the save path appended. The '{plc_path_all_empty}' placeholder can be used in the COMMAND to override path position and have the COMMAND passed to '{cmd_prog} {cmd_flag}'; where a script no. is included ('{plc_path_all_n}') the save path of that script is applied.")
+      format!("Each script is saved with the default output directory ('{path_dir}'), source file stem and OUTPUT EXTENSION, or a PATH overriding stem and/or directory, then the COMMAND is run with the save path appended. The '{plc_path_all_empty}' placeholder can be used in the COMMAND to override path position and have the COMMAND passed to '{cmd_prog} {cmd_flag}'; where a script no. is included ('{plc_path_all_n}') the save path of that script is applied. Any number of placeholders can be used, each referring to a script saved in the same run, and any text other than a script no. between placeholder head and tail is taken as is, as is a head or tail preceded by '\\'.")
     ),
     (
       "plus",
       format!("The '{sig_stop}' signal can be used before the EXTENSION etc. to avoid both the save and run stages, or before the COMMAND to avoid run only. The '{plc_path_dir}' placeholder can be used in a full PATH to denote the default or overridden output directory name.")
     ),
     (
-      "pipe", format!("One or more file paths can be piped to aliesce to append the content at each to the source as a script, auto-preceded by a tag line with a base '{sig_stop}', then exit.")
+      "cmds",
+      format!("Where the COMMAND is omitted, any command set for the OUTPUT EXTENSION is run in its place{cmd_example}. The '{sig_stop}' signal can still be used to avoid the run stage.")
+    ),
+    (
+      "pipe", format!("One or more file paths can be piped to aliesce to append the content at each to the source as a script, auto-preceded by a tag line with a base '{sig_stop}', then exit. Paths are taken one per line, or else separated by whitespace where no file exists for the line, or delimited by NUL where any. By default paths are read only where stdin is a pipe or file - see '--stdin'.")
+    ),
+    (
+      "exit", String::from("The exit code is 0 on success, 2 for a usage error, 3 for a configuration file or preface error, 4 for a source file error, 5 where the source is locked, 6 for a failure to save a file and 7 for a failure to run a file.")
     )
   ];
 
   ConfigMessages {
     repository: HashMap::from(repository),
-    keys_notes: Vec::from(["file", "line", "main", "plus", "pipe"])
+    keys_notes: Vec::from(["file", "line", "main", "plus", "cmds", "pipe", "exit"])
   }
 }
 
 /* - MAIN */
 
 fn main() {
+  if let Err(error) = run() { error_handle(error) }
+}
+
+fn run() -> Result<(), Error> {
 
   /* INITIAL SETUP */
 
-  let defaults = ConfigDefaults::from(DEFAULTS);
+  /* update defaults for any user configuration file */
+  let mut defaults = ConfigDefaults::from(DEFAULTS, COMMANDS);
+  if let Some(path_cfg_user) = config_path_user_get() {
+    config_file_apply(&mut defaults, &path_cfg_user)?;
+  }
   let settings = settings_new(&defaults);
   let messages = messages_new(&defaults);
 
@@ -177,66 +377,102 @@ fn main() {
     defaults,
     settings,
     messages,
-    receipts: HashMap::new()
+    receipts: HashMap::new(),
+    text_src: None,
+    dir_base: None
   };
 
   /* update config for args passed to command */
   let args_on_cli = env::args()
     .skip(1)
     .collect::<Vec<_>>();
-  let config_base = Config::receive(config_init, &args_remaining_cli_apply, args_on_cli);
+  let config_base = Config::receive(config_init, &args_remaining_cli_apply, args_on_cli)?;
 
   /* SOURCE APPEND VIA STDIN */
 
-  if_paths_on_stdin_push_then_exit(&config_base);
+  if_paths_on_stdin_push_then_exit(&config_base)?;
 
   /* SOURCE UPDATE VIA ARGS OR PROCESS TO OUTPUT */
 
-  let source = source_get(&config_base);
+  let source = source_get(&config_base)?;
 
   /* update config for args passed in source */
-  let args_in_src = source.preface
-    .split_whitespace()
-    .map(|part| part.trim().to_string())
-    .filter(|part| !part.is_empty())
-    .collect::<Vec<_>>();
-  let config_full = Config::receive(config_base, &args_remaining_src_apply, args_in_src);
+  let (args_in_src, args_in_profile) = preface_args_get(&source, &config_base)?;
+  let tag_head_base = config_base.get_or("head", "tag_head");
+  let words_cli = config_base.receipts.keys().cloned().collect::<Vec<_>>();
+  let config_profile = Config::receive(config_base, &args_remaining_src_apply, args_in_profile)?;
+  let mut config_full = Config::receive(config_profile, &args_remaining_src_apply, args_in_src)?;
+
+  /* set any source directory as base of relative output paths */
+  config_full.dir_base = path_base_get(&source, &config_full)?;
 
-  if_change_in_args_make_then_exit(&source, &config_full);
+  if_config_in_args_print_then_exit(&config_full, &words_cli);
 
-  /* get outputs and output subset as context */
-  let outputs = outputs_get(source, &config_full);
+  /* reread source if tag head set in source */
+  let source = if tag_head_base != config_full.get_or("head", "tag_head") { source_get(&config_full)? } else { source };
+
+  if_completions_in_args_print_then_exit(&config_full, &source);
+  if_change_in_args_make_then_exit(&config_full)?;
+
+  /* get outputs and output subset as context, checking each placeholder has path */
+  let outputs = outputs_get(&source, &config_full);
   let context = context_get(&outputs);
+  placeholders_check(&source, &outputs, &context, &config_full)?;
+
+  /* get hash of content last saved per output path, for check before each save,
+     from backup directory alongside source, or within output directory where source on stdin */
+  let path_src = config_full.get_or("path_src", "path_src");
+  let dir_manifest = if "-" == path_src {
+    Path::new(&config_full.get_or("dest", "path_dir")).join(config_full.defaults.expect("path_bak_dir"))
+  } else {
+    source_backup_dir_get(&path_src, &config_full)
+  };
+  let is_forced = config_full.receipts.contains_key("force");
+  let mut manifest = OutputManifest::load(dir_manifest.join(PATH_MANIFEST), is_forced)?;
+
+  /* print preview for each output if dry run, else check each file may be saved before any output applied */
+  if config_full.receipts.contains_key("dry-run") {
+    return outputs
+      .iter()
+      .try_for_each(|o| o.preview(&context, &manifest));
+  }
+  outputs
+    .iter()
+    .try_for_each(|o| o.check(&manifest))?;
 
   /* print output if text or process if file */
   outputs
     .iter()
-    .for_each(|o| o.apply(&context))
+    .try_for_each(|o| o.apply(&context, &mut manifest))
 }
 
 /* - data structures */
 
 struct Source {
+  shebang: String,
   preface: String,
   scripts: Vec<Script>
 }
 
+/* script with no. and no. in source file of tag line, with body from line following */
+#[derive(Clone)]
 struct Script {
-  n:    usize,
-  line: String,
-  body: String
+  n:      usize,
+  line_n: usize,
+  line:   String,
+  body:   String
 }
 
 impl Script {
-  fn new(n: usize, text: String) -> Script {
+  fn new(n: usize, line_n: usize, text: String) -> Script {
 
     let mut lines = text.lines();
-    let line = lines.nth(0).unwrap().to_string();
+    let line = lines.next().unwrap_or_default().to_string();
     let body = lines
       .collect::<Vec<_>>()
       .join("\n");
 
-    Script { n, line, body }
+    Script { n, line_n, line, body }
   }
 }
 
@@ -244,169 +480,211 @@ impl Script {
 
 /*   - general */
 
-fn if_paths_on_stdin_push_then_exit(config: &Config) {
-
-  let (tx, rx) = mpsc::channel();
-
-  /* spawn thread for blocking read and send bytes */
-  thread::spawn(move || {
-    let mut stdin = io::stdin();
-    let mut bfr;
-    loop {
-      bfr = [0; 512];
-      match stdin.read(&mut bfr) {
-        Ok(0)  => break,
-        Ok(_)  => tx.send(bfr).unwrap(),
-        Err(e) => {
-          format!("Failed (read error: '{e}')");
-          process::exit(1);
-        }
-      }
-    }
-  });
-  thread::sleep(Duration::from_millis(5));
-
-  /* receive bytes and build string */
-  let mut recvd = String::new();
-  loop {
-    thread::sleep(Duration::from_micros(25));
-    match rx.try_recv() {
-      Ok(b)  => recvd.push_str(&String::from_utf8(b.to_vec()).unwrap()),
-      Err(_) => break
-    };
-  }
+fn if_paths_on_stdin_push_then_exit(config: &Config) -> Result<(), Error> {
 
-  /* process lines in string to paths */
-  let paths = recvd
-    .trim_end_matches("\0")
-    .split_whitespace()
-    .map(|s| s.to_string())
-    .filter(|s| !s.is_empty())
-    .collect::<Vec<_>>();
+  /* handle stdin read for source or left to scripts, whether set or not pipe or file */
+  if "-" == config.get_or("path_src", "path_src") { return Ok(()) };
+  let mode = config.get_or("stdin", "stdin_mode");
+  match mode.as_str() {
+    "paths" => (),
+    "pass"  => return Ok(()),
+    "auto"  => if !stdin_is_input() { return Ok(()) },
+    _       => return Err(Error::new(ErrorKind::Config, format!("Not reading stdin with mode '{mode}' (not 'auto', 'paths' or 'pass')")))
+  };
+
+  /* read bytes until end of input */
+  let mut bytes = Vec::new();
+  io::stdin()
+    .lock()
+    .read_to_end(&mut bytes)
+    .map_err(|e| Error::new(ErrorKind::Source, "Not appending scripts for paths on stdin").with("read", e))?;
 
   /* handle script pushes for any paths */
+  let paths = stdin_paths_get(&bytes);
   if !paths.is_empty() {
+    let tag_data = config.get_or("stop", "sig_stop");
     for path in paths {
-      let strs = Vec::from([
-        config.defaults.expect("sig_stop").to_string(),
-        path
-      ]);
-      script_push(&config, strs);
+      script_push(config, &tag_data, &path)?;
     }
     process::exit(0);
   };
+  Ok(())
 }
 
-fn if_change_in_args_make_then_exit(source: &Source, config: &Config) {
+fn if_config_in_args_print_then_exit(config: &Config, words_cli: &[String]) {
 
-  let args = match config.receipts.get("edit") {
-    Some(ConfigReceiptVal::Strs(s)) => s.to_owned(),
-    _                            => Vec::new()
-  };
+  if !config.receipts.contains_key("config") { return };
 
-  /* handle source changes for any args */
-  if !args.is_empty() {
+  let origin_default_get = |key: &str| config.defaults.origins
+    .get(key)
+    .map(|path| format!("config file '{path}'"))
+    .unwrap_or(String::from("compiled default"));
+  let origin_receipt_get = |word: &str| String::from(if words_cli.iter().any(|w| word == w) { "command line" } else { "source preface" });
 
-    let arg_n = args[0].parse::<usize>().expect("parse no. for option 'edit'");
-    let arg_line = &args[1];
-    let arg_line_tagged = tag_head_add(arg_line, &config);
+  /* get defaults incl. commands per extension, each with origin */
+  let mut defaults = config.defaults.repository
+    .iter()
+    .map(|(key, val)| (key.to_string(), val.to_owned(), origin_default_get(key)))
+    .collect::<Vec<_>>();
+  defaults.sort();
+  let mut commands = config.defaults.commands
+    .iter()
+    .map(|(ext, cmd)| {
+      let key = format!("cmd.{ext}");
+      let origin = origin_default_get(&key);
+      (key, cmd.to_owned(), origin)
+    })
+    .collect::<Vec<_>>();
+  commands.sort();
 
-    /* update tag line and join whole */
-    let source_scripts = source.scripts.iter()
-      .map(|script| {
-        let Script { n, line, body } = script;
-        let line_tagged = tag_head_add(line, &config);
-        format!("{}\n{body}\n", if arg_n == *n { &arg_line_tagged } else { &line_tagged })
-      })
-      .collect::<String>();
+  /* get options received, in settings order, each with origin */
+  let options = config.settings
+    .iter()
+    .map(|setting| setting.word.to_owned())
+    .chain([String::from("path_src")])
+    .filter_map(|word| {
+      let val = match config.receipts.get(&word)? {
+        ConfigReceiptVal::Bool       => String::from("on"),
+        ConfigReceiptVal::Ints(ints) => ints.iter().map(|int| int.to_string()).collect::<Vec<_>>().join(","),
+        ConfigReceiptVal::Strs(strs) => strs.iter().map(|str| format!("'{str}'")).collect::<Vec<_>>().join(" ")
+      };
+      let key = if "path_src" == word { String::from("SOURCE") } else { format!("--{word}") };
+      Some((key, val, origin_receipt_get(&word)))
+    })
+    .collect::<Vec<_>>();
+
+  /* get values derived from option received or else default, each with origin */
+  let derived = [
+    ("source path",      "path_src",  "path_src"    ),
+    ("output directory", "dest",      "path_dir"    ),
+    ("tag head",         "head",      "tag_head"    ),
+    ("tag tail",         "tail",      "tag_tail"    ),
+    ("stop signal",      "stop",      "sig_stop"    ),
+    ("dir placeholder",  "plc-dir",   "plc_path_dir"),
+    ("path placeholder", "plc-all",   "plc_path_all"),
+    ("shell",            "shell",     "cmd_prog"    ),
+    ("shell signal",     "shell-sig", "sig_shell"   ),
+    ("backups kept",     "keep",      "bak_count"   ),
+    ("stdin mode",       "stdin",     "stdin_mode"  ),
+    ("path base",        "base",      "path_base"   )
+  ]
+    .into_iter()
+    .map(|(name, key_receipt, key_default)| {
+      let mut val = config.get_or(key_receipt, key_default);
+      if "shell" == name && 1 == val.split_whitespace().count() {
+        val = format!("{val} {}", config.defaults.expect("cmd_flag"));
+      }
+      if let Some(dir_base) = config.dir_base.as_ref().filter(|_| "output directory" == name) {
+        val = Path::new(dir_base).join(val).display().to_string();
+      }
+      let origin = if config.receipts.contains_key(key_receipt) { origin_receipt_get(key_receipt) } else { origin_default_get(key_default) };
+      (name.to_string(), val, origin)
+    })
+    .collect::<Vec<_>>();
 
-    let text = format!("{}{source_scripts}", source.preface);
+  /* print each group with values aligned */
+  let groups = [("Defaults", defaults), ("Commands", commands), ("Options", options), ("Derived", derived)];
+  let width_key = groups.iter().flat_map(|(_, items)| items.iter().map(|item| item.0.chars().count())).max().unwrap_or(0);
+  let width_val = groups.iter().flat_map(|(_, items)| items.iter().map(|item| item.1.chars().count())).max().unwrap_or(0);
+  for (title, items) in groups {
+    if items.is_empty() { continue };
+    println!("{title}:");
+    for (key, val, origin) in items {
+      println!("  {key:width_key$}  {val:width_val$}  ({origin})");
+    }
+  }
+  process::exit(0);
+}
 
-    /* write source to file, with backup to then removal of temporary directory */
-    let path_src      = config.get_or("path_src", "path_src");
-    let path_src_inst = Path::new(&path_src);
-    let path_src_stem = path_src_inst.file_stem().unwrap().to_str().unwrap();
-    let path_src_ext  = path_src_inst.extension().unwrap().to_str().unwrap();
+fn if_completions_in_args_print_then_exit(config: &Config, source: &Source) {
 
-    let secs = SystemTime::now()
-      .duration_since(SystemTime::UNIX_EPOCH)
-      .unwrap()
-      .as_secs();
+  if !config.receipts.contains_key("completions") { return };
 
-    let path_tmp_dir = config.defaults.expect("path_tmp_dir");
-    let path_tmp = format!("{path_tmp_dir}/{path_src_stem}_{secs}.{path_src_ext}");
+  /* print for each script the number and label or else data, tab-separated */
+  for script in &source.scripts {
+    let (label, data) = tag_line_split(&script.line, config);
+    let desc = if label.trim().is_empty() { data } else { label.trim() };
+    println!("{}\t{desc}", script.n);
+  }
+  process::exit(0);
+}
 
-    fs::create_dir_all(&path_tmp_dir)
-      .unwrap_or_else(|_| panic!("create temporary directory '{path_tmp_dir}' for source backup"));
-    fs::copy(&path_src, &path_tmp)
-      .unwrap_or_else(|_| panic!("copy source as backup to '{path_tmp}'"));
-    fs::write(&path_src, text)
-      .unwrap_or_else(|_| panic!("write updated source to '{path_src}'"));
-    fs::remove_dir_all(&path_tmp_dir)
-      .unwrap_or_else(|_| panic!("remove temporary directory '{path_tmp_dir}'"));
+fn if_change_in_args_make_then_exit(config: &Config) -> Result<(), Error> {
 
-    println!("Updated tag line for script no. {arg_n} to '{arg_line_tagged}'");
-    process::exit(0);
+  let changes: [(&str, &SourceChange); 6] = [
+    ("edit",    &source_change_edit),
+    ("label",   &source_change_label),
+    ("retag",   &source_change_retag),
+    ("extract", &source_change_extract),
+    ("copy",    &source_change_copy),
+    ("merge",   &source_change_merge)
+  ];
+
+  /* handle source change for first option with args, rereading source once locked */
+  for (key, change) in changes {
+    if let Some(ConfigReceiptVal::Strs(args)) = config.receipts.get(key) {
+      let _lock = source_lock(&config.get_or("path_src", "path_src"), config)?;
+      change(&source_get(config)?, config, args)?;
+      process::exit(0);
+    };
   };
+  Ok(())
+}
+
+fn source_get(config: &Config) -> Result<Source, Error> {
+  source_read(&config.get_or("path_src", "path_src"), config)
 }
 
-fn source_get(config: &Config) -> Source {
+fn source_read(path_src: &str, config: &Config) -> Result<Source, Error> {
 
-  let doc_line_file = config.messages.expect("file");
-  let doc_line_line = config.messages.expect("line");
+  let tag_head = &config.get_or("head", "tag_head");
 
-  /* load source file content as string or exit early */
-  let sections = fs::read_to_string(&config.get_or("path_src", "path_src"))
-    .unwrap_or_else(|e| error_handle((
-      &format!("Not parsing source file '{}'", config.get_or("path_src", "path_src")),
-      Some("read"),
-      Some(e)
-    )))
-    /* set any init option text with tag head to placeholder */
+  /* load source file content, or source read from stdin, as string or exit early */
+  let text = match (path_src, &config.text_src) {
+    ("-", Some(text)) => text.to_owned(),
+    ("-", None)       => return Err(Error::new(ErrorKind::Usage, "Not parsing source on stdin (only as SOURCE)")),
+    _                 => fs::read_to_string(path_src)
+      .map_err(|e| Error::new(ErrorKind::Source, format!("Not parsing source file '{path_src}'")).with("read", e))?
+  };
+  let sections = text
+    /* get args section plus each source string (script with tag line minus tag head) per line begun with tag head,
+       each with no. in file of first line */
     .lines()
-    .map(|l| if doc_line_file == l { "plc_doc_line_file" } else { l })
-    .map(|l| if doc_line_line == l { "plc_doc_line_line" } else { l })
-    .collect::<Vec<_>>()
-    .join("\n")
-    /* get args section plus each source string (script with tag line minus tag head) numbered */
-    .split(config.defaults.expect("tag_head"))
-    .map(|part| part.to_owned())
     .enumerate()
-    /* remove any shebang line */
-    .map(|(i, part)| if 0 == i && part.len() >= 2 && "#!" == &part[..2] {
-        (i, part.splitn(2, '\n').last().unwrap().to_string())
-      } else {
-        (i, part)
-    })
-    .collect::<Vec<_>>();
+    .fold(Vec::from([(1, String::new())]), |mut acc, (i, l)| {
+      match l.strip_prefix(tag_head.as_str()) {
+        Some(rest) => acc.push((i + 1, format!("{rest}\n"))),
+        _          => acc.last_mut().unwrap().1.push_str(&format!("{l}\n"))
+      };
+      acc
+    });
+
+  /* set aside any shebang line */
+  let (shebang, preface) = match sections[0].1.starts_with("#!") {
+    true => match sections[0].1.split_once('\n') {
+      Some((line, rest)) => (format!("{line}\n"), rest.to_string()),
+      None               => (format!("{}\n", sections[0].1), String::new())
+    },
+    _    => (String::new(), sections[0].1.to_owned())
+  };
 
-  let preface = sections[0].1
-    /* restore any init option text set to placeholder */
-    .replace("plc_doc_line_file", doc_line_file)
-    .replace("plc_doc_line_line", doc_line_line);
-  let scripts = Vec::from(sections.split_at(1).1)
-    .iter()
-    .map(|section| Script::new(section.0, section.1.to_owned()))
+  let scripts = sections
+    .into_iter()
+    .enumerate()
+    .skip(1)
+    .map(|(n, (line_n, text))| Script::new(n, line_n, text))
     .collect::<Vec<_>>();
 
-  Source { preface, scripts }
+  Ok(Source { shebang, preface, scripts })
 }
 
 fn inputs_parse(script: &Script, config: &Config) -> Output {
 
-  let Script { n, line, body } = script;
-  let Config { defaults, receipts, .. } = config;
+  let Script { n, line, body, .. } = script;
+  let Config { receipts, .. } = config;
 
   /* get label and data from tag line */
-  let line_sections = match line.find(defaults.expect("tag_tail")) {
-    Some(i) => line.split_at(i + 1),
-    None    => ("", line.as_str())
-  };
-  let line_label = line_sections.0
-    .split(defaults.expect("tag_tail"))
-    .nth(0)
-    .unwrap(); /* untrimmed */
-  let line_data  = line_sections.1.trim();
+  let (line_label, line_data) = tag_line_split(line, config);
 
   /* handle option - list - print only */
   if receipts.contains_key("list") {
@@ -423,31 +701,41 @@ fn inputs_parse(script: &Script, config: &Config) -> Output {
 
   /* handle data absent or bypass */
   if data.is_empty() {
-    let text = format!("No tag data found for script no. {n}");
+    let text = script_location_get(script, None, config).describe(&format!("No tag data found for script no. {n}"));
     return Output::Text(OutputText::Stderr(text));
   }
-  if data.get(0).unwrap() == defaults.expect("sig_stop") {
-    let text = format!("Bypassing script no. {n} ({} applied)", defaults.expect("sig_stop"));
+  let sig_stop = config.get_or("stop", "sig_stop");
+  if *data.first().unwrap() == sig_stop {
+    let text = format!("Bypassing script no. {n} ({sig_stop} applied)");
     return Output::Text(OutputText::Stderr(text));
   }
 
-  Output::File(OutputFile::new(data, body.to_owned(), n.to_owned(), config))
+  /* get any shell from tag line prefix, with any flags comma-separated */
+  let sig_shell = config.get_or("shell-sig", "sig_shell");
+  let (shell, data) = match data.first().unwrap().strip_prefix(sig_shell.as_str()) {
+    Some(shell) if !shell.is_empty() => (Some(shell.replace(',', " ")), data[1..].to_vec()),
+    _                                => (None, data)
+  };
+  if data.is_empty() {
+    let summary = format!("No tag data found for script no. {n} after shell '{}'", shell.unwrap());
+    let text = script_location_get(script, None, config).describe(&summary);
+    return Output::Text(OutputText::Stderr(text));
+  }
+
+  Output::File(OutputFile::new(data, body.to_owned(), n.to_owned(), shell, config))
 }
 
-fn outputs_get(source: Source, config: &Config) -> Vec<Output> {
+fn outputs_get(source: &Source, config: &Config) -> Vec<Output> {
   source.scripts
     .iter()
     /* handle option - only - allow subset */
-    .filter(|script| !config.receipts.contains_key("only") || match config.receipts.get("only").unwrap() {
-      ConfigReceiptVal::Ints(ns) => ns.contains(&script.n),
-      _                       => false
-    })
+    .filter(|script| script_selected(script, config))
     /* parse input set to output instance */
-    .map(|script| inputs_parse(script, &config))
+    .map(|script| inputs_parse(script, config))
     .collect::<Vec<_>>()
 }
 
-fn context_get(outputs: &Vec<Output>) -> HashMap<usize, String> {
+fn context_get(outputs: &[Output]) -> HashMap<usize, String> {
   outputs
     .iter()
     /* get each output path with script no. */
@@ -457,1257 +745,4213 @@ fn context_get(outputs: &Vec<Output>) -> HashMap<usize, String> {
     })
 }
 
-/*   - argument applicators */
-
-fn setting_dest_apply(_: &Config, strs: Vec<String>) -> ConfigReceiptVal {
-  ConfigReceiptVal::Strs(strs)
+fn placeholders_check(source: &Source, outputs: &[Output], context: &HashMap<usize, String>, config: &Config) -> Result<(), Error> {
+  for output in outputs {
+    let Output::File(OutputFile { init: OutputFileInit::Code(code), n, .. }) = output else { continue };
+    /* handle placeholder for script no. with no output path, i.e. script missing or excluded, pointing at placeholder in tag line */
+    for part in &code.parts {
+      let OutputFileCmdPart::Path(n_plc, plc) = part else { continue };
+      if context.contains_key(n_plc) { continue };
+      let reason = if source.scripts.iter().any(|script| *n_plc == script.n) { "not saved in this run" } else { "not in source" };
+      let error = Error::new(ErrorKind::Source, format!("Not running file no. {n} (placeholder '{plc}' for script no. {n_plc}, {reason})"));
+      return Err(match source.scripts.iter().find(|script| *n == script.n) {
+        Some(script) => error.at(script_location_get(script, Some(plc), config)),
+        None         => error
+      });
+    }
+  }
+  Ok(())
 }
 
-fn setting_edit_apply(_: &Config, strs: Vec<String>) -> ConfigReceiptVal {
-  ConfigReceiptVal::Strs(strs)
+/*   - source changes */
+
+type SourceChange = dyn Fn(&Source, &Config, &[String]) -> Result<(), Error>;
+
+fn source_change_edit(source: &Source, config: &Config, args: &[String]) -> Result<(), Error> {
+
+  let arg_n = script_n_parse(&args[0], "edit")?;
+  let arg_line = &args[1];
+  let arg_line_tagged = tag_head_add(arg_line, config);
+
+  if !source.scripts.iter().any(|script| arg_n == script.n) {
+    return Err(Error::new(ErrorKind::Usage, format!("Not updating tag line for script no. {arg_n} (no such script)")));
+  }
+
+  let text = source_compose(source, config, &HashMap::from([(arg_n, arg_line_tagged.clone())]));
+  source_write(&config.get_or("path_src", "path_src"), text, config)?;
+
+  println!("Updated tag line for script no. {arg_n} to '{arg_line_tagged}'");
+  Ok(())
 }
 
-fn setting_list_apply(_0: &Config, _1: Vec<String>) -> ConfigReceiptVal {
-  ConfigReceiptVal::Bool
+fn source_change_label(source: &Source, config: &Config, args: &[String]) -> Result<(), Error> {
+
+  let arg_n = script_n_parse(&args[0], "label")?;
+  let arg_text = args[1].trim();
+
+  let script = source.scripts
+    .iter()
+    .find(|script| arg_n == script.n)
+    .ok_or_else(|| Error::new(ErrorKind::Usage, format!("Not updating label for script no. {arg_n} (no such script)")))?;
+
+  /* handle label text which would change how tag line or source is parsed */
+  let tag_head = &config.get_or("head", "tag_head");
+  let tag_tail = &config.get_or("tail", "tag_tail");
+  if arg_text.contains(['\n', '\r']) || arg_text.contains(tag_head.as_str()) || arg_text.contains(tag_tail.as_str()) {
+    let summary = format!("Not updating label for script no. {arg_n} to '{arg_text}' (includes tag head '{tag_head}', tag tail '{tag_tail}' or line break)");
+    return Err(Error::new(ErrorKind::Usage, summary));
+  }
+
+  /* retain tag line data and set label, if any */
+  let (_, line_data) = tag_line_split(&script.line, config);
+  let line_tagged = if arg_text.is_empty() {
+    format!("{tag_head} {line_data}")
+  } else {
+    format!("{tag_head} {arg_text} {tag_tail} {line_data}")
+  };
+
+  let text = source_compose(source, config, &HashMap::from([(arg_n, line_tagged)]));
+  source_write(&config.get_or("path_src", "path_src"), text, config)?;
+
+  if arg_text.is_empty() {
+    println!("Removed label for script no. {arg_n}");
+  } else {
+    println!("Updated label for script no. {arg_n} to '{arg_text}'");
+  }
+  Ok(())
 }
 
-fn setting_only_apply(_: &Config, strs: Vec<String>) -> ConfigReceiptVal {
-  let val_ints = strs[0]
-    .trim()
-    .split(',')
-    .flat_map(|val_str| {
-      let vals = val_str
-        .trim()
-        .split('-')
-        .map(|item| item.parse::<usize>().expect("parse subset for option 'only'"))
-        .collect::<Vec<_>>();
-      if vals.len() > 1 {
-        (vals[0]..(vals[1] + 1))
-          .collect::<Vec<_>>()
+fn source_change_retag(source: &Source, config: &Config, args: &[String]) -> Result<(), Error> {
+
+  let arg_pattern     = &args[0];
+  let arg_replacement = &args[1];
+
+  let regex = Regex::new(arg_pattern)
+    .map_err(|e| Error::new(ErrorKind::Usage, format!("Not retagging for pattern '{arg_pattern}' (invalid pattern: '{e}')")))?;
+
+  /* get new tag line for each selected script with tag line data changed */
+  let tag_head = &config.get_or("head", "tag_head");
+  let tag_tail = &config.get_or("tail", "tag_tail");
+  let lines_new = source.scripts
+    .iter()
+    .filter(|script| script_selected(script, config))
+    .map(|script| {
+      let (line_label, line_data) = tag_line_split(&script.line, config);
+      let line_data_new = regex.replace_all(line_data, arg_replacement.as_str());
+      if line_data_new == line_data { return Ok(None) };
+      let line = if line_label.is_empty() {
+        format!(" {line_data_new}")
       } else {
-         vals
+        format!("{line_label}{tag_tail} {line_data_new}")
+      };
+
+      /* handle tag line data which would change how tag line or source is parsed */
+      if line_data_new.contains(['\n', '\r']) || tag_line_split(&line, config).1 != line_data_new.trim() {
+        let summary = format!("Not retagging script no. {} to '{line_data_new}' (includes line break or tag tail '{tag_tail}' changing label)", script.n);
+        return Err(Error::new(ErrorKind::Usage, summary));
       }
+      Ok(Some((script.n, format!("{tag_head}{line}"))))
     })
+    .collect::<Result<Vec<_>, Error>>()?
+    .into_iter()
+    .flatten()
     .collect::<Vec<_>>();
-  ConfigReceiptVal::Ints(val_ints)
+
+  if lines_new.is_empty() {
+    println!("No tag line data matched for pattern '{arg_pattern}'");
+    return Ok(());
+  }
+
+  /* show each change as diff */
+  lines_new
+    .iter()
+    .for_each(|(n, line_new)| {
+      let script = source.scripts.iter().find(|script| *n == script.n).unwrap();
+      println!("{n}:\n- {tag_head}{}\n+ {line_new}", script.line);
+    });
+
+  /* handle option - list - show only */
+  if config.receipts.contains_key("list") {
+    println!("Not updating source (list applied)");
+    return Ok(());
+  }
+
+  let count = lines_new.len();
+  let text = source_compose(source, config, &HashMap::from_iter(lines_new));
+  source_write(&config.get_or("path_src", "path_src"), text, config)?;
+
+  println!("Updated tag line for {count} script(s)");
+  Ok(())
 }
 
-fn setting_push_apply(config: &Config, strs: Vec<String>) -> ConfigReceiptVal {
-  script_push(config, strs);
-  process::exit(0);
+fn source_change_extract(source: &Source, config: &Config, args: &[String]) -> Result<(), Error> {
+  source_scripts_send(source, config, args, true)
 }
 
-fn setting_init_apply(config: &Config, _: Vec<String>) -> ConfigReceiptVal {
+fn source_change_copy(source: &Source, config: &Config, args: &[String]) -> Result<(), Error> {
+  source_scripts_send(source, config, args, false)
+}
 
-  let src = &config.get_or("path_src", "path_src");
-  let summary_failure = format!("Not creating template source file at '{src}'");
+fn source_change_merge(source: &Source, config: &Config, args: &[String]) -> Result<(), Error> {
 
-  /* exit early if source file exists */
-  if fs::metadata(src).is_ok() {
-    error_handle((
-      &format!("{summary_failure} (path exists)"),
-      None,
-      None
-    ))
-  };
+  let path_src   = config.get_or("path_src", "path_src");
+  let path_other = &args[0];
 
-  let content = format!("\
-      <any arguments to aliesce (run 'aliesce --help' for options)>\n\n\
-      Notes on source file format:\n\n\
-      {}\n\n{}\n\n{}\n\n\
-      Appending scripts via stdin:\n\n\
-      {}\n\n\
-      Tag line and script section:\n\n\
-      {}\n\n<script>\n\
-    ",
-    config.messages.expect("file"),
-    config.messages.expect("main"),
-    config.messages.expect("plus"),
-    config.messages.expect("pipe"),
-    config.messages.expect("line")
-  );
+  if paths_same(path_other, &path_src) {
+    return Err(Error::new(ErrorKind::Usage, format!("Not merging source file '{path_other}' (same as source)")));
+  }
 
-  fs::write(src, content)
-    .unwrap_or_else(|e| error_handle((
-      &summary_failure,
-      Some("write"),
-      Some(e)
-    )));
+  /* append all scripts in other source, noting any preface not carried over */
+  let other = source_read(path_other, config)?;
+  if !other.preface.trim().is_empty() && other.preface.trim() != source.preface.trim() {
+    eprintln!("Not merging preface of source file '{path_other}' (differs from preface of '{path_src}')");
+  }
 
-  println!("Created template source file at '{src}'");
-  process::exit(0);
+  let scripts = other.scripts.iter().collect::<Vec<_>>();
+  let text = source_append(&path_src, &scripts, config)?;
+  source_write(&path_src, text, config)?;
+
+  println!("Appended {} script(s) from source file '{path_other}' to '{path_src}'", scripts.len());
+  Ok(())
 }
 
-fn args_remaining_cli_apply(args_remaining: Vec<String>) -> ConfigReceipts {
-  /* set final source filename (incl. output stem) per positional arg */
-  let mut receipts = ConfigReceipts::new();
-  if !args_remaining.is_empty() {
-    let arg = args_remaining.get(0).unwrap().clone();
-    let val = ConfigReceiptVal::Strs(Vec::from([arg]));
-    receipts.insert(String::from("path_src"), val);
-  }
-  receipts
+/*   - argument applicators */
+
+fn setting_dest_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Strs(strs))
 }
 
-fn args_remaining_src_apply(_: Vec<String>) -> ConfigReceipts {
-  ConfigReceipts::new()
+fn setting_edit_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Strs(strs))
 }
 
-/* - utility functions */
+fn setting_label_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Strs(strs))
+}
 
-fn tag_head_add(line: &str, config: &Config) -> String {
-  let tag_head = config.defaults.expect("tag_head");
-  if line.len() >= 3 && line[..3] == *tag_head { line.to_string() } else { format!("{tag_head} {}", line.trim()) }
+fn setting_retag_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Strs(strs))
 }
 
-fn script_push(config: &Config, strs: Vec<String>) {
+fn setting_extract_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Strs(strs))
+}
 
-  let script_filename = &strs[1];
+fn setting_copy_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Strs(strs))
+}
 
-  /* handle read */
+fn setting_merge_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Strs(strs))
+}
 
-  let script = fs::read_to_string(script_filename)
-    .unwrap_or_else(|e| error_handle((
-      &format!("Not parsing script file '{script_filename}'"),
-      Some("read"),
-      Some(e)
-    )));
-  let tag_line = tag_head_add(&strs[0], &config);
-  let script_plus_tag_line = format!("\n{tag_line}\n\n{script}");
+fn setting_keep_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Strs(strs))
+}
 
-  /* handle write */
+fn setting_completions_apply(config: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
 
-  let summary_base = format!(
-    "tag line '{tag_line}' and content of script file '{script_filename}' to source file '{}'",
-    config.get_or("path_src", "path_src")
-  );
-  let summary_failure = format!("Not appending {summary_base}");
-  let summary_success = format!("Appended {summary_base}");
+  /* print script for shell, or defer listing of scripts until source read */
+  let script = match strs[0].as_str() {
+    "bash"    => completions_bash_get(&config.settings),
+    "zsh"     => completions_zsh_get(&config.settings),
+    "fish"    => completions_fish_get(&config.settings),
+    "scripts" => return Ok(ConfigReceiptVal::Strs(strs)),
+    kind      => return Err(Error::new(ErrorKind::Usage, format!("Not printing completions for '{kind}' (not 'bash', 'zsh', 'fish' or 'scripts')")))
+  };
+  print!("{script}");
+  process::exit(0);
+}
 
-  fs::OpenOptions::new()
-    .append(true)
-    .open(config.get_or("path_src", "path_src"))
-    .unwrap_or_else(|e| error_handle((
-      &summary_failure,
-      Some("open"),
-      Some(e)
-    )))
-    .write_all(&script_plus_tag_line.into_bytes())
-    .unwrap_or_else(|e| error_handle((
-      &summary_failure,
-      Some("write"),
-      Some(e)
-    )));
+fn setting_undo_apply(config: &Config, _: Vec<String>) -> Result<ConfigReceiptVal, Error> {
 
-  println!("{summary_success}");
+  let path_src = config.get_or("path_src", "path_src");
+  let _lock = source_lock(&path_src, config)?;
+
+  /* replace source with most recent backup */
+  let paths = source_backup_paths_get(&path_src, config);
+  let path_bak = paths
+    .last()
+    .ok_or_else(|| Error::new(ErrorKind::Source, format!("Not restoring source file '{path_src}' (no backup found)")))?;
+
+  fs::rename(path_bak, &path_src)
+    .map_err(|e| Error::new(ErrorKind::Save, format!("Not restoring source file '{path_src}' from backup '{}'", path_bak.display())).with("rename", e))?;
+
+  println!("Restored source file '{path_src}' from backup '{}'", path_bak.display());
+  process::exit(0);
 }
 
-fn error_handle(strs: (&String, Option<&str>, Option<io::Error>)) -> ! {
-  match strs {
-    (s, Some(a), Some(e)) => eprintln!("{s} ({a} error: '{e}')"),
-    (s, None,    None   ) => eprintln!("{s}"),
-    _                     => eprintln!("Failed (unknown error)")
+fn setting_syntax_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  if strs[0].trim().is_empty() || strs[0].contains(char::is_whitespace) {
+    return Err(Error::new(ErrorKind::Usage, format!("Not setting tag line syntax to '{}' (empty or includes whitespace)", strs[0])));
   }
-  process::exit(1);
+  Ok(ConfigReceiptVal::Strs(strs))
 }
 
-/* OUTPUT */
-
-mod output {
-
-  /* - imports */
+fn setting_plc_all_apply(config: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  if 1 != strs[0].matches("{}").count() || "{}" == strs[0] {
+    return Err(Error::new(ErrorKind::Usage, format!("Not setting output path placeholder to '{}' (requires '{{}}' once, with head and/or tail)", strs[0])));
+  }
+  setting_syntax_apply(config, strs)
+}
 
-  use std::fs;
-  use std::process;
-  use std::collections::HashMap;
+fn setting_cmd_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Strs(strs))
+}
 
-  use crate::config::{Config};
+fn setting_profile_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Strs(strs))
+}
 
-  /* - data structures */
+fn setting_stem_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  if strs[0].is_empty() || strs[0].contains('/') {
+    return Err(Error::new(ErrorKind::Usage, format!("Not setting output stem to '{}' (empty or includes '/')", strs[0])));
+  }
+  Ok(ConfigReceiptVal::Strs(strs))
+}
 
-  #[derive(Debug, PartialEq)]
-  pub enum Output {
-    Text(OutputText),
-    File(OutputFile)
+fn setting_base_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  if !["auto", "source", "cwd"].contains(&strs[0].as_str()) {
+    return Err(Error::new(ErrorKind::Usage, format!("Not setting path base to '{}' (not 'auto', 'source' or 'cwd')", strs[0])));
   }
+  Ok(ConfigReceiptVal::Strs(strs))
+}
 
-  impl Output {
+fn setting_stdin_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  if !["auto", "paths", "pass"].contains(&strs[0].as_str()) {
+    return Err(Error::new(ErrorKind::Usage, format!("Not setting stdin mode to '{}' (not 'auto', 'paths' or 'pass')", strs[0])));
+  }
+  Ok(ConfigReceiptVal::Strs(strs))
+}
 
-    pub fn apply(&self, context: &HashMap<usize, String>) {
-      match self {
-        Output::Text(e) => {
-          match e {
-            OutputText::Stdout(s) => {  println!("{s}"); },
-            OutputText::Stderr(s) => { eprintln!("{s}"); }
-          }
-        },
-        Output::File(s) => {
-          s.save();
-          s.exec(&context);
-        }
-      };
-    }
+fn setting_shell_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  if strs[0].trim().is_empty() {
+    return Err(Error::new(ErrorKind::Usage, "Not setting shell (empty)"));
   }
+  Ok(ConfigReceiptVal::Strs(strs))
+}
 
-  #[derive(Debug, PartialEq)]
-  pub enum OutputText {
-    Stdout(String),
-    Stderr(String)
-  }
-
-  #[derive(Debug, PartialEq)]
-  pub struct OutputFile {
-    pub data: Vec<String>,
-    pub code: String,
-    pub path: OutputFilePath,
-    pub init: OutputFileInit,
-    pub n:    usize
-  }
+fn setting_dry_run_apply(_: &Config, _: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Bool)
+}
 
-  impl OutputFile {
+fn setting_force_apply(_: &Config, _: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Bool)
+}
 
-    pub fn new(data: Vec<String>, code: String, n: usize, config: &Config) -> OutputFile {
+fn setting_config_apply(_: &Config, _: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Bool)
+}
 
-      let Config { defaults, receipts: _, .. } = config;
+fn setting_list_apply(_: &Config, _: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Bool)
+}
 
-      /* set output path parts */
+fn setting_only_apply(_: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  Ok(ConfigReceiptVal::Ints(subset_parse(&strs[0], "only")?))
+}
 
-      /* get output path parts - break first data item on '/' */
-      let mut parts_path = data.get(0).unwrap()
-        .split('/')
-        .collect::<Vec<_>>();
-      let path_dir = config.get_or("dest", "path_dir");
+fn setting_push_apply(config: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  script_push(config, &strs[0], Path::new(&strs[1]))?;
+  process::exit(0);
+}
 
-      /* handle output directory identified by directory placeholder */
-      if defaults.expect("plc_path_dir") == parts_path[0] { parts_path[0] = path_dir.as_str() };
+fn setting_push_stdin_apply(config: &Config, strs: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+  script_push_stdin(config, &strs[0])?;
+  process::exit(0);
+}
 
-      /* get output filename parts - separate last output path part and break on '.' */
-      let parts_filename = parts_path
-        .split_off(parts_path.len() - 1)
-        .last()
-        .unwrap()
-        .split('.')
-        .collect::<Vec<_>>();
-      let p_f_len = parts_filename.len();
+fn setting_init_apply(config: &Config, _: Vec<String>) -> Result<ConfigReceiptVal, Error> {
 
-      /* set as dir either remaining output path parts recombined or directory name,
-             as stem either all but last output filename part or src stem, and
-             as ext last output filename part */
-      let dir = if !parts_path.is_empty() { parts_path.join("/") } else { path_dir.to_string() };
-      let stem = if p_f_len > 1 {
-        parts_filename[..(p_f_len - 1)]
-          .join(".")
-      } else {
-        config.get_or("path_src", "path_src")
-          .split('.')
-          .nth(0)
-          .unwrap()
-          .to_string()
-      };
-      let ext = parts_filename
-        .iter()
-        .last()
-        .unwrap()
-        .to_string();
+  let src = &config.get_or("path_src", "path_src");
+  let summary_failure = format!("Not creating template source file at '{src}'");
 
-      let path = OutputFilePath{ dir, stem, ext };
+  /* exit early if source on stdin or source file exists */
+  if "-" == src {
+    return Err(Error::new(ErrorKind::Usage, format!("{summary_failure} (source on stdin, no file)")));
+  };
+  if fs::metadata(src).is_ok() {
+    return Err(Error::new(ErrorKind::Usage, format!("{summary_failure} (path exists)")));
+  };
 
-      /* set output init parts */
+  let content = format!("\
+      # Any options for aliesce, one per line with its values, e.g. '--dest out' (run 'aliesce --help' for options)\n\n\
+      # Notes on source file format:\n#\n\
+      # {}\n#\n# {}\n#\n# {}\n#\n# {}\n#\n\
+      # Appending scripts via stdin:\n#\n\
+      # {}\n#\n\
+      # Tag line and script section:\n#\n\
+      # {}\n#\n# <script>\n\
+    ",
+    config.messages.expect("file"),
+    config.messages.expect("main"),
+    config.messages.expect("plus"),
+    config.messages.expect("cmds"),
+    config.messages.expect("pipe"),
+    config.messages.expect("line")
+  );
 
-      /* handle file run precluded */
-      if data.len() == 1 {
-        let init = OutputFileInit::Text(
-          OutputText::Stderr(
-            format!("Not running file no. {n} (no values)")
-          )
-        );
-        return OutputFile { data, code, path, init, n };
-      }
-      if data.get(1).unwrap() == defaults.expect("sig_stop") {
-        let init = OutputFileInit::Text(
-          OutputText::Stderr(
-            format!("Not running file no. {n} ({} applied)", defaults.expect("sig_stop"))
-          )
-        );
-        return OutputFile { data, code, path, init, n };
-      }
+  fs::write(src, content)
+    .map_err(|e| Error::new(ErrorKind::Save, &summary_failure).with("write", e))?;
 
-      /* set as plcs any uses of output path placeholder and note presence as indicator of composite command */
-      let mut parts_placeholder = config.defaults.expect("plc_path_all").split("{}");
-      let plc_head = parts_placeholder.next().unwrap();
-      let plc_tail = parts_placeholder.next().unwrap();
-      let plc_full = Vec::from([plc_head, plc_tail]).join("");
+  println!("Created template source file at '{src}'");
+  process::exit(0);
+}
 
-      let plcs = data
-        .iter()
-        .skip(1)
-        .map(|item| {
-          /* handle request for current script output path */
-          if item.contains(&plc_full) { return (0, plc_full.to_owned()) };
-          let head_i = if let Some(i) = item.find(plc_head) { i as i8 } else { -1 };
-          let tail_i = if let Some(i) = item.find(plc_tail) { i as i8 } else { -1 };
-          /* handle request for another script output path */
-          if -1 != head_i && -1 != tail_i && head_i < tail_i {
-             let s = item
-               .chars()
-               .skip(head_i as usize)
-               .take((tail_i - head_i + 1) as usize)
-               .collect::<String>();
-             let i = s
-               .chars()
-               .skip(plc_head.len())
-               .take(s.len() - plc_full.len())
-               .collect::<String>()
-               .parse::<i8>()
-               .unwrap();
-             return (i, s)
-          };
-          /* handle no request - value to be filtered out */
-          (-1, String::new())
-        })
-        .filter(|item| -1 != item.0)
-        .collect::<Vec<_>>();
+fn args_remaining_cli_apply(config: &mut Config, args_positional: Vec<String>, args_unknown: Vec<String>) -> Result<(), Error> {
 
-      let has_placeholder = !plcs.is_empty();
+  /* handle any unknown flag or surplus positional arg */
+  if let Some(arg) = args_unknown.first() {
+    return Err(Error::new(ErrorKind::Usage, format!("Not parsing option '{arg}' (unknown, see '--help')")));
+  }
+  if let Some(arg) = args_positional.get(1) {
+    return Err(Error::new(ErrorKind::Usage, format!("Not parsing argument '{arg}' (source path already given)")));
+  }
 
-      /* set as prog either tag line second item or default, and
-             as args either Vec containing remaining items plus combined path or default flag plus remaining items joined */
-      let prog = String::from(if has_placeholder { defaults.expect("cmd_prog") } else { data.get(1).unwrap() });
-      let args = if has_placeholder {
-        Vec::from([
-          defaults.expect("cmd_flag").to_string(),
-          data
-            .iter()
-            .skip(1)
-            .map(|item| item.to_owned())
-            .collect::<Vec<_>>()
-            .join(" ")
-        ])
-      } else {
-        [
-          data
-            .iter()
-            .skip(2)
-            .map(|arg| arg.to_owned())
-            .collect::<Vec<_>>(),
-          Vec::from([path.get()])
-        ]
-          .concat()
-      };
+  /* set final source filename (incl. output stem) per positional arg, reading source text now if on stdin */
+  if let Some(arg) = args_positional.first() {
+    let val = ConfigReceiptVal::Strs(Vec::from([arg.to_owned()]));
+    config.receipts.insert(String::from("path_src"), val);
+    if "-" == arg {
+      let summary_failure = "Not parsing source on stdin";
+      let mut bytes = Vec::new();
+      io::stdin()
+        .lock()
+        .read_to_end(&mut bytes)
+        .map_err(|e| Error::new(ErrorKind::Source, summary_failure).with("read", e))?;
+      let text = String::from_utf8(bytes)
+        .map_err(|_| Error::new(ErrorKind::Source, format!("{summary_failure} (not valid UTF-8)")))?;
+      config.text_src = Some(text);
+    }
+  }
 
-      let init = OutputFileInit::Code(OutputFileInitCode { prog, args, plcs });
+  /* update defaults for any project configuration file alongside source, then settings and messages */
+  let path_cfg_project = config_path_project_get(&config.get_or("path_src", "path_src"));
+  config_file_apply(&mut config.defaults, &path_cfg_project)?;
+  config.settings = settings_new(&config.defaults);
+  config.messages = messages_new(&config.defaults);
+  Ok(())
+}
 
-      OutputFile { data, code, path, init, n }
-    }
+fn args_remaining_src_apply(_: &mut Config, _: Vec<String>, _: Vec<String>) -> Result<(), Error> {
+  Ok(())
+}
 
-    fn save(&self) {
+/* - utility functions */
 
-      let OutputFile { data: _, code, path, init: _, n: _ } = self;
-      let dir = &path.dir;
-      let path = path.get();
+fn preface_args_get(source: &Source, config: &Config) -> Result<(Vec<String>, Vec<String>), Error> {
 
-      /* add directory if none */
-      fs::create_dir_all(&dir).unwrap_or_else(|_| panic!("create directory '{dir}'"));
-      /* write script to file */
-      fs::write(&path, code).unwrap_or_else(|_| panic!("write script to '{path}'"));
-    }
+  let path_src = config.get_or("path_src", "path_src");
+  let n_offset = if source.shebang.is_empty() { 1 } else { 2 };
 
-    fn exec(&self, context: &HashMap<usize, String>) {
+  /* get any profile selected */
+  let profile = match config.receipts.get("profile") {
+    Some(ConfigReceiptVal::Strs(strs)) => strs.first().cloned(),
+    _                                  => None
+  };
 
-      let OutputFile { data: _, code: _, path: _, init, n } = self;
+  /* get args for each directive line, i.e. single option w/ values taken, skipping blank and comment lines,
+     noting each profile line and including below it only the args for any profile selected,
+     and ending at any line begun with tag head set in preface */
+  let mut tag_head = config.get_or("head", "tag_head");
+  let mut args_all = Vec::new();
+  let mut args_profile = Vec::new();
+  let mut args_profile_repeatable = Vec::new();
+  let mut profiles = Vec::new();
+  for (i, line_raw) in source.preface.lines().enumerate() {
+    if line_raw.starts_with(tag_head.as_str()) { break };
+    let line = line_raw.trim();
+    if line.is_empty() || line.starts_with('#') { continue };
+
+    /* get error for reason, pointing at start of line */
+    let location = Location {
+      path:   source_name_get(&path_src),
+      line_n: i + n_offset,
+      col_n:  line_raw[..line_raw.find(line).unwrap_or(0)].chars().count() + 1,
+      text:   line_raw.to_string()
+    };
+    let error_get = |reason: String| Error::new(ErrorKind::Config, format!("Not parsing preface line ({reason})")).at(location.clone());
 
-      match init {
+    /* handle profile line */
+    if let Some(name) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+      let name = name.trim();
+      if name.is_empty() || profiles.iter().any(|profile| name == profile) {
+        return Err(error_get(format!("profile name '{name}' empty or repeated")));
+      }
+      profiles.push(name.to_string());
+      continue;
+    }
+    let is_included = profiles.last().is_none_or(|name| Some(name) == profile.as_ref());
+    let args = preface_line_split(line)
+      .map_err(error_get)?;
+
+    /* handle content other than option, incl. unknown option */
+    let arg = &args[0];
+    if !arg.starts_with('-') || "-" == arg || "--" == arg {
+      return Err(error_get(String::from("not comment or option, see '--help'")));
+    }
+    let Some(flags) = config.flags_parse(arg) else {
+      return Err(error_get(format!("unknown option '{arg}', see '--help'")));
+    };
 
-        /* print reason file run precluded */
-        OutputFileInit::Text(e) => {
-          match e {
-            OutputText::Stdout(s) => {  println!("{s}"); },
-            OutputText::Stderr(s) => { eprintln!("{s}"); }
-          }
-        },
-        /* run script from file */
-        OutputFileInit::Code(c) => {
-          let OutputFileInitCode { prog, args, plcs } = c;
+    /* handle option for command line only, e.g. acting once then exiting */
+    if let Some((i_setting, _)) = flags.iter().find(|(i, _)| config.settings[*i].is_cli_only) {
+      let name = config.settings[*i_setting].name_get(arg);
+      return Err(error_get(format!("'{name}' for command line only")));
+    }
 
-          let args_full = if plcs.is_empty() {
-            args.to_owned()
-          } else {
-            let mut cmd = if 0 == plcs.len() { String::new() } else { args[1].to_owned() };
-            plcs
-              .iter()
-              .for_each(|plc| {
-                let path = if 0 == plc.0 { context.get(n).unwrap() } else { context.get(&(plc.0 as usize)).unwrap() };
-                cmd = cmd.replace(plc.1.as_str(), path.as_str()).to_owned();
-              });
-            Vec::from([args[0].to_owned(), cmd])
-          };
+    /* handle value count other than that taken by option, incl. any further option */
+    let (i_setting, val_inline) = flags.last().unwrap();
+    let setting = &config.settings[*i_setting];
+    let strs_len = setting.strs.len().saturating_sub(usize::from(val_inline.is_some()));
+    if strs_len != args.len() - 1 {
+      let strs_taken = if setting.strs.is_empty() { String::from("no values") } else { setting.strs.join(" ") };
+      return Err(error_get(format!("'{arg}' takes {strs_taken}, one option per line")));
+    }
 
-          process::Command::new(&prog)
-            .args(args_full)
-            .spawn()
-            .unwrap_or_else(|_| panic!("run file with '{prog}'"))
-            .wait_with_output()
-            .unwrap_or_else(|_| panic!("await output from '{prog}'"));
-        }
-      }
+    /* update tag head if set in preface for args included and not on command line */
+    if !is_included { continue };
+    if "head" == setting.word && !config.receipts.contains_key("head") {
+      tag_head = val_inline.to_owned().or(args.last().cloned()).unwrap();
+    }
+    match (profiles.is_empty(), setting.is_repeatable) {
+      (true, _)      => args_all.extend(args),
+      (false, true)  => args_profile_repeatable.extend(args),
+      (false, false) => args_profile.extend(args)
     }
   }
 
-  #[derive(Debug, PartialEq)]
-  pub struct OutputFilePath {
-    pub dir:  String,
-    pub stem: String,
-    pub ext:  String
+  /* handle profile selected but absent */
+  if let Some(name) = profile.filter(|name| !profiles.contains(name)) {
+    let profiles_listed = if profiles.is_empty() { String::from("none listed") } else { profiles.join(", ") };
+    return Err(Error::new(ErrorKind::Usage, format!("Not applying profile '{name}' (not in preface of source file '{path_src}', {profiles_listed})")));
   }
 
-  impl OutputFilePath {
-    pub fn get(&self) -> String {
-      format!("{}/{}.{}", &self.dir, &self.stem, &self.ext)
+  /* add profile args repeatable after others, for combination, and return others apart, for precedence */
+  args_all.extend(args_profile_repeatable);
+  Ok((args_all, args_profile))
+}
+
+fn preface_line_split(line: &str) -> Result<Vec<String>, String> {
+
+  let mut args = Vec::new();
+  let mut arg: Option<String> = None;
+  let mut quote: Option<char> = None;
+  let mut chars = line.chars();
+
+  /* split on whitespace outside quotes, taking quoted text in single quotes as is
+     and in double quotes or unquoted with backslash escaping next char */
+  while let Some(c) = chars.next() {
+    match (quote, c) {
+      (None, c) if c.is_whitespace() => { if let Some(arg) = arg.take() { args.push(arg) }; },
+      (None, '\'') | (None, '"')     => { quote = Some(c); arg.get_or_insert_with(String::new); },
+      (Some(q), c) if q == c         => { quote = None; },
+      (Some('\''), c)                => { arg.get_or_insert_with(String::new).push(c); },
+      (_, '\\')                      => {
+        let Some(c_next) = chars.next() else { return Err(String::from("escape at end of line")) };
+        let arg = arg.get_or_insert_with(String::new);
+        if quote.is_some() && !['"', '\\'].contains(&c_next) { arg.push('\\') };
+        arg.push(c_next);
+      },
+      (_, c)                         => { arg.get_or_insert_with(String::new).push(c); }
     }
   }
+  if let Some(q) = quote { return Err(format!("no closing {q}")) };
+  if let Some(arg) = arg { args.push(arg) };
 
-  #[derive(Debug, PartialEq)]
-  pub enum OutputFileInit {
-    Text(OutputText),
-    Code(OutputFileInitCode)
-  }
+  Ok(args)
+}
 
-  #[derive(Debug, PartialEq)]
-  pub struct OutputFileInitCode {
-    pub prog: String,
-    pub args: Vec<String>,
-    pub plcs: Vec<(i8, String)>
-  }
+fn completions_bash_get(settings: &ConfigSettings) -> String {
+
+  /* get case for each option taking values, listing kinds of value taken, and all flags */
+  let cases = settings
+    .iter()
+    .filter(|setting| !setting.strs.is_empty())
+    .map(|setting| {
+      let kinds = setting.kinds.join(" ");
+      format!("      --{}|-{}) kinds=({kinds}); break;;\n", setting.word, setting.char)
+    })
+    .collect::<String>();
+  let flags = settings
+    .iter()
+    .map(|setting| format!("--{} -{}", setting.word, setting.char))
+    .collect::<Vec<_>>()
+    .join(" ");
+
+  format!(r#"# bash completion for aliesce, generated by 'aliesce --completions bash'
+_aliesce_scripts() {{
+  local src word
+  for word in "${{COMP_WORDS[@]:1:COMP_CWORD-1}}"; do [[ -f "$word" ]] && src="$word"; done
+  aliesce --completions scripts ${{src:+"$src"}} 2>/dev/null | cut -f1
+}}
+_aliesce() {{
+  local cur="${{COMP_WORDS[COMP_CWORD]}}" i kinds=()
+  for (( i = COMP_CWORD - 1; i > 0; i-- )); do
+    case "${{COMP_WORDS[i]}}" in
+{cases}      -*) break;;
+    esac
+  done
+  case "${{kinds[COMP_CWORD - i - 1]}}" in
+    scripts) COMPREPLY=($(compgen -W "$(_aliesce_scripts)" -- "$cur"));;
+    file)    COMPREPLY=($(compgen -f -- "$cur"));;
+    dir)     COMPREPLY=($(compgen -d -- "$cur"));;
+    kind)    COMPREPLY=($(compgen -W "bash zsh fish scripts" -- "$cur"));;
+    mode)    COMPREPLY=($(compgen -W "auto paths pass" -- "$cur"));;
+    base)    COMPREPLY=($(compgen -W "auto source cwd" -- "$cur"));;
+    none)    COMPREPLY=();;
+    *)       if [[ "$cur" == -* ]]; then COMPREPLY=($(compgen -W "{flags}" -- "$cur")); else COMPREPLY=($(compgen -f -- "$cur")); fi;;
+  esac
+}}
+complete -F _aliesce aliesce
+"#)
 }
 
-/* CONFIG, incl. argument_handling */
+fn completions_zsh_get(settings: &ConfigSettings) -> String {
 
-mod config {
+  /* get spec for each option, with action for each value taken */
+  let specs = settings
+    .iter()
+    .map(|setting| {
+      let desc = setting.desc
+        .replace('\'', "'\\''")
+        .replace('[', "\\[")
+        .replace(']', "\\]");
+      let vals = setting.strs
+        .iter()
+        .zip(&setting.kinds)
+        .map(|(val, kind)| {
+          let action = match kind.as_str() {
+            "scripts" => "_aliesce_scripts",
+            "file"    => "_files",
+            "dir"     => "_files -/",
+            "kind"    => "(bash zsh fish scripts)",
+            "mode"    => "(auto paths pass)",
+            "base"    => "(auto source cwd)",
+            _         => " "
+          };
+          format!(":{val}:{action}")
+        })
+        .collect::<String>();
+      format!("    '*'{{-{},--{}}}'[{desc}]{vals}' \\\n", setting.char, setting.word)
+    })
+    .collect::<String>();
+
+  format!(r#"#compdef aliesce
+# zsh completion for aliesce, generated by 'aliesce --completions zsh'
+_aliesce_scripts() {{
+  local src word
+  local -a scripts
+  for word in ${{words[2,CURRENT-1]}}; do [[ -f $word ]] && src=$word; done
+  scripts=(${{(f)"$(aliesce --completions scripts $src 2>/dev/null)"}})
+  scripts=(${{scripts//$'\t'/:}})
+  _describe 'script' scripts
+}}
+_aliesce() {{
+  _arguments -s -S \
+{specs}    '*:source file:_files'
+}}
+if [[ $funcstack[1] == _aliesce ]]; then _aliesce "$@"; else compdef _aliesce aliesce; fi
+"#)
+}
 
-  /* - imports */
+fn completions_fish_get(settings: &ConfigSettings) -> String {
 
-  use std::process;
-  use std::collections::HashMap;
+  /* get line for each option, with completion for any first value taken */
+  let lines = settings
+    .iter()
+    .map(|setting| {
+      let desc = setting.desc.replace('\\', "\\\\").replace('\'', "\\'");
+      let args = match setting.kinds.first().map(String::as_str) {
+        None            => "",
+        Some("scripts") => " -x -a '(__aliesce_scripts)'",
+        Some("file")    => " -r -F",
+        Some("dir")     => " -x -a '(__fish_complete_directories)'",
+        Some("kind")    => " -x -a 'bash zsh fish scripts'",
+        Some("mode")    => " -x -a 'auto paths pass'",
+        Some("base")    => " -x -a 'auto source cwd'",
+        Some(_)         => " -x"
+      };
+      format!("complete -c aliesce -s {} -l {}{args} -d '{desc}'\n", setting.char, setting.word)
+    })
+    .collect::<String>();
+
+  format!(r#"# fish completion for aliesce, generated by 'aliesce --completions fish'
+function __aliesce_scripts
+    set -l src
+    for word in (commandline -opc)[2..-1]
+        test -f "$word"; and set src $word
+    end
+    aliesce --completions scripts $src 2>/dev/null
+end
+{lines}"#)
+}
 
-  /* - data structures */
+fn config_path_user_get() -> Option<PathBuf> {
+  env::var_os("XDG_CONFIG_HOME")
+    .filter(|dir| !dir.is_empty())
+    .map(PathBuf::from)
+    .or_else(|| env::var_os("HOME").map(|dir| PathBuf::from(dir).join(".config")))
+    .map(|dir| dir.join(PATH_CFG_USER))
+}
 
-  pub struct Config<'a> {
-    pub defaults: ConfigDefaults<'a>,
-    pub settings: ConfigSettings,
-    pub receipts: ConfigReceipts,
-    pub messages: ConfigMessages<'a>
-  }
+fn config_path_project_get(path_src: &str) -> PathBuf {
+  Path::new(path_src)
+    .parent()
+    .unwrap_or(Path::new(""))
+    .join(PATH_CFG_PROJECT)
+}
 
-  impl Config<'_> {
+fn config_file_apply(defaults: &mut ConfigDefaults, path: &Path) -> Result<(), Error> {
 
-    pub fn receive(mut config: Config<'static>, handle_remaining: &ArgHandler, args: Vec<String>) -> Config<'static> {
+  let summary_failure = format!("Not loading configuration file '{}'", path.display());
 
-      let args_count: usize = args.len();
+  /* handle file absent */
+  let text = match fs::read_to_string(path) {
+    Ok(text)                                      => text,
+    Err(e) if io::ErrorKind::NotFound == e.kind() => return Ok(()),
+    Err(e)                                        => return Err(Error::new(ErrorKind::Config, &summary_failure).with("read", e))
+  };
 
-      /* for each flag in args, queue setting call with any values and tally */
-      let mut settings_queued = Vec::new();
-      let mut settings_count = 0;
-      if args_count > 0 {
-        for setting in &config.settings {
-          for j in 0..args_count {
-            if ["--", &setting.word].concat() == args[j] || ["-", &setting.char].concat() == args[j] {
-              let strs_len = setting.strs.len();
-              let strs = args[(j + 1)..(j + strs_len + 1)].to_vec();
-              settings_queued.push((&setting.word, &setting.call, strs));
-              settings_count = settings_count + 1 + strs_len;
-            };
-          };
-        };
-      };
-      /* handle any remaining arguments */
-      let args_remaining = args[(settings_count)..].to_vec();
-      let receipts_args_remaining = handle_remaining(args_remaining);
-      config.receipts.extend(receipts_args_remaining);
-
-      /* make any queued setting calls */
-      if !settings_queued.is_empty() {
-        for opt_queued in &settings_queued {
-          let (word, call, strs) = &opt_queued;
-          let value = call(&config, strs.to_vec());
-          config.receipts.insert(String::from(*word), value);
-        }
-      }
-      config
+  /* update default for key on each line, skipping blank and comment lines */
+  for (i, line) in text.lines().enumerate() {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') { continue };
+    let (key, val) = line
+      .split_once('=')
+      .map(|(key, val)| (key.trim(), val.trim()))
+      .ok_or_else(|| Error::new(ErrorKind::Config, format!("{summary_failure} (no '=' on line {})", i + 1)))?;
+    /* handle command for output extension, removing if empty */
+    if let Some(ext) = key.strip_prefix("cmd.") {
+      if val.is_empty() { defaults.commands.remove(ext); } else { defaults.commands.insert(ext.to_string(), val.to_string()); }
     }
-
-    pub fn get_or(&self, key_receipt: &str, key_default: &str) -> String {
-      if self.receipts.contains_key(key_receipt) {
-        if let ConfigReceiptVal::Strs(val_strs) = self.receipts.get(key_receipt).unwrap() {
-          return val_strs
-            .get(0)
-            .expect(&format!("get string for receipt value '{key_receipt}' from configuration"))
-            .to_string();
-        }
-      }
-      String::from(self.defaults.expect(key_default))
+    else if !defaults.update(key, val) {
+      return Err(Error::new(ErrorKind::Config, format!("{summary_failure} (unknown key '{key}' on line {})", i + 1)));
     }
+    /* note file as origin of value */
+    defaults.origins.insert(key.to_string(), path.display().to_string());
   }
+  Ok(())
+}
 
-  pub struct ConfigDefaults<'a> {
-    pub repository: HashMap<&'a str, &'a str>
+fn tag_line_split<'a>(line: &'a str, config: &Config) -> (&'a str, &'a str) {
+
+  let tag_tail = &config.get_or("tail", "tag_tail");
+
+  /* get label (untrimmed) and data (trimmed) either side of any tag tail */
+  match line.find(tag_tail) {
+    Some(i) => (&line[..i], line[(i + tag_tail.len())..].trim()),
+    None    => ("", line.trim())
   }
+}
 
-  impl<'a> ConfigDefaults<'a> {
+/* get directory of source file as base of relative output paths, if any, per path base set */
+fn path_base_get(source: &Source, config: &Config) -> Result<Option<String>, Error> {
 
-    pub fn from<T>(defaults: T) -> Self
-    where
-      T: IntoIterator<Item=(&'a str, &'a str)>
-    {
-      Self {
-        repository: HashMap::from_iter(defaults)
-      }
-    }
+  let base = config.get_or("base", "path_base");
+  let is_source = match base.as_str() {
+    "source" => true,
+    "cwd"    => false,
+    "auto"   => !source.shebang.is_empty(),
+    _        => return Err(Error::new(ErrorKind::Config, format!("Not setting path base to '{base}' (not 'auto', 'source' or 'cwd')")))
+  };
 
-    pub fn expect(&self, key: &str) -> &str {
-      self.repository
-        .get(key)
-        .expect(&format!("get default value '{key}'"))
-    }
+  /* handle source in current directory or on stdin */
+  let path_src = config.get_or("path_src", "path_src");
+  let dir = Path::new(&path_src).parent().map(|dir| dir.display().to_string()).unwrap_or_default();
+  Ok(if is_source && "-" != path_src && !dir.is_empty() { Some(dir) } else { None })
+}
+
+/* get whether paths refer to same file, as resolved where both exist, e.g. 'src.txt' and './src.txt', else as given */
+fn paths_same(path_a: &str, path_b: &str) -> bool {
+  match (fs::canonicalize(path_a), fs::canonicalize(path_b)) {
+    (Ok(a), Ok(b)) => a == b,
+    _              => Path::new(path_a) == Path::new(path_b)
   }
+}
 
-  pub type ConfigSettings = Vec<ConfigSetting>;
-  pub type ConfigReceipts = HashMap<String, ConfigReceiptVal>;
+/* get source path for display, as '<stdin>' for source on stdin */
+fn source_name_get(path_src: &str) -> String {
+  if "-" == path_src { String::from("<stdin>") } else { path_src.to_string() }
+}
 
-  #[derive(PartialEq, Eq)]
-  pub enum ConfigReceiptVal {
-    Bool,
-    Ints(Vec<usize>),
-    Strs(Vec<String>)
+/* get location in source of item in tag line data, or else of end of tag line */
+fn script_location_get(script: &Script, item: Option<&str>, config: &Config) -> Location {
+
+  let text = format!("{}{}", config.get_or("head", "tag_head"), script.line).trim_end().to_string();
+  let (_, data) = tag_line_split(&script.line, config);
+  let i_data = text.rfind(data).unwrap_or(text.len());
+  let i = item
+    .and_then(|item| text[i_data..].find(item).map(|i| i_data + i))
+    .unwrap_or(text.len());
+
+  Location {
+    path:   source_name_get(&config.get_or("path_src", "path_src")),
+    line_n: script.line_n,
+    col_n:  text[..i].chars().count() + 1,
+    text
   }
+}
 
-  pub struct ConfigMessages<'a> {
-    pub repository: HashMap<&'a str, String>,
-    pub keys_notes: Vec<&'a str>
-  }
+fn subset_parse(subset: &str, word: &str) -> Result<Vec<usize>, Error> {
+  let ns = subset
+    .trim()
+    .split(',')
+    .map(|val_str| {
+      let vals = val_str
+        .trim()
+        .split('-')
+        .map(|item| script_n_parse(item, word))
+        .collect::<Result<Vec<_>, _>>()?;
+      if vals.len() > 1 && vals[0] > vals[1] {
+        return Err(Error::new(ErrorKind::Usage, format!("Not parsing range '{}' for option '{word}' (start after end)", val_str.trim())));
+      }
+      Ok(if vals.len() > 1 { (vals[0]..(vals[1] + 1)).collect::<Vec<_>>() } else { vals })
+    })
+    .collect::<Result<Vec<_>, Error>>()?;
+  Ok(ns.concat())
+}
 
-  impl ConfigMessages<'_> {
+/* get whether stdin is pipe or file, i.e. neither terminal nor other device, e.g. '/dev/null' */
+fn stdin_is_input() -> bool {
+  let stdin = io::stdin();
+  if stdin.is_terminal() { return false };
+  #[cfg(unix)]
+  {
+    use std::os::fd::AsFd;
+    use std::os::unix::fs::FileTypeExt;
+    stdin.as_fd()
+      .try_clone_to_owned()
+      .map(fs::File::from)
+      .and_then(|file| file.metadata())
+      .is_ok_and(|meta| meta.file_type().is_fifo() || meta.is_file())
+  }
+  #[cfg(not(unix))]
+  {
+    true
+  }
+}
 
-    pub fn expect(&self, key: &str) -> &str {
-      self.repository
-        .get(key)
-        .expect(&format!("get message '{key}'"))
-    }
+/* get paths delimited by NUL where any, e.g. from 'find -print0', each as is,
+   else by line, each line as is where file exists or else each whitespace-delimited part */
+fn stdin_paths_get(bytes: &[u8]) -> Vec<PathBuf> {
 
-    pub fn compose_notes(&self) -> Vec<String> {
-      self.keys_notes
-        .iter()
-        .map(|k|
-          self.repository
-            .get(k)
-            .expect(&format!("get message '{k}' from configuration for notes"))
-            .to_string()
-        )
-        .collect()
-    }
+  if bytes.contains(&0) {
+    return bytes
+      .split(|byte| 0 == *byte)
+      .filter(|entry| !entry.is_empty())
+      .map(path_from_bytes)
+      .collect();
   }
 
-  type ConfigSettingCall = dyn Fn(&Config, Vec<String>) -> ConfigReceiptVal;
+  bytes
+    .split(|byte| b'\n' == *byte)
+    .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+    .flat_map(|line| {
+      let path = path_from_bytes(line);
+      if path.is_file() { return Vec::from([path]) };
+      line
+        .split(u8::is_ascii_whitespace)
+        .filter(|part| !part.is_empty())
+        .map(path_from_bytes)
+        .collect()
+    })
+    .collect()
+}
 
-  pub struct ConfigSetting {
-    pub word: String,
-    pub char: String,
-    pub strs: Vec<String>,
-    pub desc: String,
-        call: Box<ConfigSettingCall>
+/* get path from bytes as is where supported, else with any invalid UTF-8 replaced */
+fn path_from_bytes(bytes: &[u8]) -> PathBuf {
+  #[cfg(unix)]
+  {
+    use std::os::unix::ffi::OsStrExt;
+    PathBuf::from(std::ffi::OsStr::from_bytes(bytes))
   }
+  #[cfg(not(unix))]
+  {
+    PathBuf::from(String::from_utf8_lossy(bytes).into_owned())
+  }
+}
 
-  impl ConfigSetting {
-    pub fn new(word: &str, char: &str, val_strs: &[&str], desc: &str, call: &'static ConfigSettingCall) -> ConfigSetting {
-      let strs = if !val_strs.is_empty() {
-        val_strs
-          .iter()
-          .map(|&s| String::from(s))
-          .collect::<Vec<_>>()
-      } else {
-        Vec::new()
-      };
-      ConfigSetting {
-        word: String::from(word),
-        char: String::from(char),
-        strs,
-        desc: String::from(desc),
-        call: Box::new(call)
+fn script_n_parse(item: &str, word: &str) -> Result<usize, Error> {
+  item
+    .trim()
+    .parse::<usize>()
+    .map_err(|_| Error::new(ErrorKind::Usage, format!("Not parsing script no. '{item}' for option '{word}' (not a number)")))
+}
+
+fn script_selected(script: &Script, config: &Config) -> bool {
+  match config.receipts.get("only") {
+    Some(ConfigReceiptVal::Ints(ns)) => ns.contains(&script.n),
+    Some(_)                          => false,
+    None                             => true
+  }
+}
+
+fn tag_head_add(line: &str, config: &Config) -> String {
+  let tag_head = &config.get_or("head", "tag_head");
+  if line.starts_with(tag_head.as_str()) { line.to_string() } else { format!("{tag_head} {}", line.trim()) }
+}
+
+fn source_compose(source: &Source, config: &Config, lines_new: &HashMap<usize, String>) -> String {
+
+  let tag_head = &config.get_or("head", "tag_head");
+
+  /* update any tag lines, retaining others as is, and join whole */
+  let source_scripts = source.scripts.iter()
+    .map(|script| {
+      let Script { n, line, body, .. } = script;
+      match lines_new.get(n) {
+        Some(line_new) => format!("{line_new}\n{body}\n"),
+        None           => format!("{tag_head}{line}\n{body}\n")
       }
+    })
+    .collect::<String>();
+
+  format!("{}{}{source_scripts}", source.shebang, source.preface)
+}
+
+fn source_append(path_src: &str, scripts: &[&Script], config: &Config) -> Result<String, Error> {
+
+  let tag_head = &config.get_or("head", "tag_head");
+
+  let text = fs::read_to_string(path_src)
+    .map_err(|e| Error::new(ErrorKind::Source, format!("Not parsing source file '{path_src}'")).with("read", e))?;
+  let join = if text.is_empty() || text.ends_with('\n') { "" } else { "\n" };
+
+  /* add each script with tag line and body as is */
+  let source_scripts = scripts
+    .iter()
+    .map(|Script { line, body, .. }| format!("{tag_head}{line}\n{body}\n"))
+    .collect::<String>();
+
+  Ok(format!("{text}{join}{source_scripts}"))
+}
+
+fn source_scripts_send(source: &Source, config: &Config, args: &[String], is_move: bool) -> Result<(), Error> {
+
+  let path_src  = config.get_or("path_src", "path_src");
+  let arg_ns    = subset_parse(&args[0], if is_move { "extract" } else { "copy" })?;
+  let path_dest = &args[1];
+  let verb      = if is_move { "Moved" } else { "Copied" };
+
+  if paths_same(path_dest, &path_src) {
+    return Err(Error::new(ErrorKind::Usage, format!("Not sending scripts to source file '{path_dest}' (same as source)")));
+  }
+
+  let (scripts_sent, scripts_kept): (Vec<&Script>, Vec<&Script>) = source.scripts
+    .iter()
+    .partition(|script| arg_ns.contains(&script.n));
+
+  if scripts_sent.is_empty() {
+    return Err(Error::new(ErrorKind::Usage, format!("Not sending scripts to source file '{path_dest}' (none in subset '{}')", args[0])));
+  }
+
+  let _lock = source_lock(path_dest, config)?;
+
+  /* add scripts to destination, creating with shebang and preface of source if none, noting preface not carried over if any */
+  let text_dest = if fs::metadata(path_dest).is_ok() {
+    let dest = source_read(path_dest, config)?;
+    if !source.preface.trim().is_empty() && source.preface.trim() != dest.preface.trim() {
+      eprintln!("Not copying preface of source file '{path_src}' (differs from preface of '{path_dest}')");
     }
-    pub fn new_version() -> ConfigSetting {
-      ConfigSetting::new("version", "v", &[], "show name and version number then exit", &setting_version_apply)
+    source_append(path_dest, &scripts_sent, config)?
+  } else {
+    let dest = Source {
+      shebang: source.shebang.to_owned(),
+      preface: source.preface.to_owned(),
+      scripts: scripts_sent.iter().map(|&script| script.clone()).collect()
+    };
+    source_compose(&dest, config, &HashMap::new())
+  };
+  source_write(path_dest, text_dest, config)?;
+
+  /* remove scripts from source if moved */
+  if is_move {
+    let source_kept = Source {
+      shebang: source.shebang.to_owned(),
+      preface: source.preface.to_owned(),
+      scripts: scripts_kept.iter().map(|&script| script.clone()).collect()
+    };
+    let text = source_compose(&source_kept, config, &HashMap::new());
+    source_write(&path_src, text, config)?;
+  }
+
+  let ns = scripts_sent
+    .iter()
+    .map(|script| script.n.to_string())
+    .collect::<Vec<_>>()
+    .join(", ");
+  println!("{verb} script(s) no. {ns} from source file '{path_src}' to '{path_dest}'");
+  Ok(())
+}
+
+fn source_write(path_src: &str, text: String, config: &Config) -> Result<(), Error> {
+
+  source_backup(path_src, config)?;
+
+  /* write source to file alongside then rename, for atomic replacement */
+  let millis = millis_get();
+  let path_tmp_src = format!("{path_src}.{millis}.tmp");
+
+  let summary_failure = format!("Not updating source file '{path_src}'");
+
+  fs::write(&path_tmp_src, text)
+    .map_err(|e| Error::new(ErrorKind::Save, &summary_failure).with("write", e))?;
+  fs::rename(&path_tmp_src, path_src)
+    .map_err(|e| {
+      fs::remove_file(&path_tmp_src).ok();
+      Error::new(ErrorKind::Save, &summary_failure).with("rename", e)
+    })
+}
+
+fn millis_get() -> u128 {
+  SystemTime::now()
+    .duration_since(SystemTime::UNIX_EPOCH)
+    .map(|duration| duration.as_millis())
+    .unwrap_or_default()
+}
+
+fn source_lock(path_src: &str, config: &Config) -> Result<fs::File, Error> {
+
+  /* handle source on stdin, with no file to change */
+  if "-" == path_src {
+    return Err(Error::new(ErrorKind::Usage, "Not changing source on stdin (no file, use a source file path)"));
+  }
+
+  let path_lock_dir = source_backup_dir_get(path_src, config);
+  let path_lock = path_lock_dir.join(format!(
+    "{}.lock",
+    Path::new(path_src).file_name().unwrap_or_default().to_string_lossy()
+  ));
+
+  let summary_failure = format!("Not changing source file '{path_src}'");
+
+  /* open lock file alongside backups and take advisory lock for process lifetime */
+  fs::create_dir_all(&path_lock_dir)
+    .map_err(|e| Error::new(ErrorKind::Lock, &summary_failure).with("create directory", e))?;
+  let file = fs::OpenOptions::new()
+    .create(true)
+    .truncate(false)
+    .write(true)
+    .open(&path_lock)
+    .map_err(|e| Error::new(ErrorKind::Lock, &summary_failure).with("open lock", e))?;
+
+  match file.try_lock() {
+    Ok(())                            => Ok(file),
+    Err(fs::TryLockError::WouldBlock) => Err(Error::new(
+      ErrorKind::Lock,
+      format!("{summary_failure} (locked by another process via '{}')", path_lock.display())
+    )),
+    Err(fs::TryLockError::Error(e))   => Err(Error::new(ErrorKind::Lock, &summary_failure).with("lock", e))
+  }
+}
+
+fn source_backup(path_src: &str, config: &Config) -> Result<(), Error> {
+
+  let keep = config.get_or("keep", "bak_count");
+  let bak_count = keep
+    .parse::<usize>()
+    .map_err(|_| Error::new(ErrorKind::Usage, format!("Not backing up source file '{path_src}' (no. to keep '{keep}' not a number)")))?;
+
+  /* handle backups disabled or source yet to be created */
+  if 0 == bak_count || fs::metadata(path_src).is_err() { return Ok(()) };
+
+  /* copy source to backup directory with timestamp */
+  let millis = millis_get();
+
+  let path_src_inst = Path::new(path_src);
+  let path_src_stem = path_src_inst.file_stem().unwrap_or_default().to_string_lossy();
+  let path_bak_dir  = source_backup_dir_get(path_src, config);
+  let path_bak      = path_bak_dir.join(match path_src_inst.extension() {
+    Some(ext) => format!("{path_src_stem}_{millis}.{}", ext.to_string_lossy()),
+    None      => format!("{path_src_stem}_{millis}")
+  });
+
+  let summary_failure = format!("Not backing up source file '{path_src}' to '{}'", path_bak.display());
+
+  fs::create_dir_all(&path_bak_dir)
+    .map_err(|e| Error::new(ErrorKind::Save, &summary_failure).with("create directory", e))?;
+  fs::copy(path_src, &path_bak)
+    .map_err(|e| Error::new(ErrorKind::Save, &summary_failure).with("copy", e))?;
+
+  /* remove oldest backups beyond no. kept */
+  let paths = source_backup_paths_get(path_src, config);
+  for path in paths.iter().take(paths.len().saturating_sub(bak_count)) {
+    fs::remove_file(path)
+      .map_err(|e| Error::new(ErrorKind::Save, format!("Not removing backup '{}'", path.display())).with("remove", e))?;
+  }
+  Ok(())
+}
+
+fn source_backup_dir_get(path_src: &str, config: &Config) -> PathBuf {
+  Path::new(path_src)
+    .parent()
+    .unwrap_or(Path::new(""))
+    .join(config.defaults.expect("path_bak_dir"))
+}
+
+fn source_backup_paths_get(path_src: &str, config: &Config) -> Vec<PathBuf> {
+
+  let path_src_inst = Path::new(path_src);
+  let path_src_stem = path_src_inst.file_stem().unwrap_or_default().to_string_lossy();
+  let path_src_ext  = path_src_inst.extension().map(|ext| format!(".{}", ext.to_string_lossy()));
+
+  let entries = match fs::read_dir(source_backup_dir_get(path_src, config)) {
+    Ok(entries) => entries,
+    Err(_)      => return Vec::new()
+  };
+
+  /* get each backup of source with timestamp, oldest first */
+  let mut backups = entries
+    .filter_map(|entry| {
+      let path = entry.ok()?.path();
+      let name = path.file_name()?.to_str()?;
+      let rest = name.strip_prefix(path_src_stem.as_ref())?.strip_prefix('_')?;
+      let time = match &path_src_ext {
+        Some(ext) => rest.strip_suffix(ext.as_str())?,
+        None      => rest
+      };
+      Some((time.parse::<u128>().ok()?, path))
+    })
+    .collect::<Vec<_>>();
+  backups.sort();
+
+  backups
+    .into_iter()
+    .map(|(_, path)| path)
+    .collect()
+}
+
+fn script_push(config: &Config, tag_data: &str, path_script: &Path) -> Result<(), Error> {
+
+  let script_filename = path_script.display();
+
+  /* handle read */
+
+  let script = fs::read_to_string(path_script)
+    .map_err(|e| Error::new(ErrorKind::Usage, format!("Not parsing script file '{script_filename}'")).with("read", e))?;
+
+  script_append(config, tag_data, &script, &format!("content of script file '{script_filename}'"))
+}
+
+fn script_push_stdin(config: &Config, tag_data: &str) -> Result<(), Error> {
+
+  let summary_failure = "Not parsing script content on stdin";
+
+  /* handle read */
+
+  let stdin = io::stdin();
+  if stdin.is_terminal() {
+    return Err(Error::new(ErrorKind::Usage, format!("{summary_failure} (stdin is a terminal)")));
+  }
+
+  let mut bytes = Vec::new();
+  stdin
+    .lock()
+    .read_to_end(&mut bytes)
+    .map_err(|e| Error::new(ErrorKind::Usage, summary_failure).with("read", e))?;
+  let script = String::from_utf8(bytes)
+    .map_err(|_| Error::new(ErrorKind::Usage, format!("{summary_failure} (not valid UTF-8)")))?;
+  if script.trim().is_empty() {
+    return Err(Error::new(ErrorKind::Usage, format!("{summary_failure} (none found)")));
+  }
+
+  script_append(config, tag_data, &script, "content on stdin")
+}
+
+fn script_append(config: &Config, tag_data: &str, script: &str, script_desc: &str) -> Result<(), Error> {
+
+  let tag_line = tag_head_add(tag_data, config);
+  let script_plus_tag_line = format!("\n{tag_line}\n\n{script}");
+
+  /* handle write */
+
+  let summary_base = format!(
+    "tag line '{tag_line}' and {script_desc} to source file '{}'",
+    config.get_or("path_src", "path_src")
+  );
+  let summary_failure = format!("Not appending {summary_base}");
+  let summary_success = format!("Appended {summary_base}");
+
+  let path_src = config.get_or("path_src", "path_src");
+  let _lock = source_lock(&path_src, config)?;
+
+  let text = fs::read_to_string(&path_src)
+    .map_err(|e| Error::new(ErrorKind::Source, &summary_failure).with("read", e))?;
+  source_write(&path_src, format!("{text}{script_plus_tag_line}"), config)?;
+
+  println!("{summary_success}");
+  Ok(())
+}
+
+fn error_handle(error: Error) -> ! {
+  eprintln!("{error}");
+  process::exit(error.kind.code());
+}
+
+/* OUTPUT */
+
+mod output {
+
+  /* - imports */
+
+  use std::io;
+  use std::fs;
+  use std::process;
+  use std::path::{Path, PathBuf};
+  use std::collections::HashMap;
+
+  use crate::config::{Config, ConfigReceiptVal};
+  use crate::error::{
+    Error,
+    ErrorKind
+  };
+
+  /* - data structures */
+
+  #[derive(Debug, PartialEq)]
+  pub enum Output {
+    Text(OutputText),
+    File(OutputFile)
+  }
+
+  impl Output {
+
+    pub fn apply(&self, context: &HashMap<usize, String>, manifest: &mut OutputManifest) -> Result<(), Error> {
+      match self {
+        Output::Text(e) => e.print(),
+        Output::File(s) => {
+          s.save(manifest)?;
+          s.exec(context)?;
+        }
+      };
+      Ok(())
     }
-    pub fn new_help() -> ConfigSetting {
-      ConfigSetting::new("help", "h", &[], "show usage, flags available and notes then exit", &setting_help_apply)
+
+    /* check file may be saved, before any output applied */
+    pub fn check(&self, manifest: &OutputManifest) -> Result<(), Error> {
+      match self {
+        Output::Text(_) => Ok(()),
+        Output::File(s) => s.check(manifest)
+      }
+    }
+
+    pub fn preview(&self, context: &HashMap<usize, String>, manifest: &OutputManifest) -> Result<(), Error> {
+      match self {
+        Output::Text(e) => { e.print(); Ok(()) },
+        Output::File(s) => s.preview(context, manifest)
+      }
     }
   }
 
-  type ArgHandler = dyn Fn(Vec<String>) -> ConfigReceipts;
+  #[derive(Debug, PartialEq)]
+  pub enum OutputText {
+    Stdout(String),
+    Stderr(String)
+  }
 
-  /* - argument applicator ('help') */
+  impl OutputText {
+    fn print(&self) {
+      match self {
+        OutputText::Stdout(s) => {  println!("{s}"); },
+        OutputText::Stderr(s) => { eprintln!("{s}"); }
+      }
+    }
+  }
 
-  fn setting_version_apply(_0: &Config, _1: Vec<String>) -> ConfigReceiptVal {
-    println!("{}", name_and_version_get());
-    process::exit(0);
+  #[derive(Debug, PartialEq)]
+  pub struct OutputFile {
+    pub data: Vec<String>,
+    pub code: String,
+    pub path: OutputFilePath,
+    pub init: OutputFileInit,
+    pub n:    usize
   }
 
-  fn setting_help_apply(config: &Config, _: Vec<String>) -> ConfigReceiptVal {
+  impl OutputFile {
+
+    pub fn new(data: Vec<String>, code: String, n: usize, shell: Option<String>, config: &Config) -> OutputFile {
 
-    let line_length_max = 80;
+      let Config { defaults, receipts: _, .. } = config;
 
-    /* set value substrings and max length */
-    let strs_strs = config.settings
-      .iter()
-      .map(|o| o.strs.join(" "))
-      .collect::<Vec<_>>();
-    let strs_strs_max = strs_strs.iter()
-      .fold(0, |acc, s| if s.len() > acc { s.len() } else { acc });
-    let flag_strs = config.settings
-      .iter()
-      .map(|o| format!("-{}, --{}", o.char, o.word))
-      .collect::<Vec<_>>();
-    let flag_strs_max = flag_strs
-      .iter()
-      .fold(0, |acc, s| if s.len() > acc { s.len() } else { acc });
+      /* set output path parts */
 
-    /* generate title line */
-    let title_line = format!("{}", line_center_with_fill(&name_and_version_get(), line_length_max, "-"));
+      /* get output path parts - break first data item on '/' */
+      let mut parts_path = data.first().unwrap()
+        .split('/')
+        .collect::<Vec<_>>();
+      let path_dir = config.get_or("dest", "path_dir");
 
-    /* generate usage text */
-    let usage_opts_part = config.settings
-      .iter()
-      .filter(|o| o.word != "version" && o.word != "help") /* avoid duplication */
-      .enumerate() /* yield also index (i) */
-      .map(|(i, o)| format!(
-        "[--{}/-{}{}]",
-        o.word,
-        o.char,
-        if strs_strs[i].is_empty() { String::from("") } else { [" ", &strs_strs[i]].concat() })
-      )
-      .collect::<Vec<_>>()
-      .join(" ");
-    let usage_opts_head = line_break_and_indent(&format!("{usage_opts_part} [SOURCE]"), 15, line_length_max, false);
-    let usage_opts_tail = line_break_and_indent(&format!("/ --version/-v / --help/-h"), 15, line_length_max, true);
-    let usage_text = format!("Usage: aliesce {usage_opts_head}\n{usage_opts_tail}");
+      /* handle output directory identified by directory placeholder */
+      if config.get_or("plc-dir", "plc_path_dir") == parts_path[0] { parts_path[0] = path_dir.as_str() };
 
-    /* generate flags text */
-    let flags_list = config.settings
-      .iter()
-      .enumerate() /* yield also index (i) */
-      .map(|(i, o)| {
-        let desc = line_break_and_indent(&o.desc, flag_strs_max + strs_strs_max + 2, line_length_max, false);
-        format!(" {}  {:w$}  {desc}", flag_strs[i], strs_strs[i], w = flag_strs_max - o.word.len())
-      })
-      .collect::<Vec<_>>()
-      .join("\n");
-    let flags_text = format!("Flags:\n{flags_list}");
+      /* get output filename parts - separate last output path part and break on '.' */
+      let parts_filename = parts_path
+        .split_off(parts_path.len() - 1)
+        .last()
+        .unwrap()
+        .split('.')
+        .collect::<Vec<_>>();
+      let p_f_len = parts_filename.len();
 
-    /* generate notes text */
-    let notes_body = config.messages.compose_notes()
-      .iter()
-      .map(|l| line_break_and_indent(&l, 1, line_length_max, true))
-      .collect::<Vec<_>>()
-      .join("\n\n");
-    let notes_text = format!("Notes:\n{notes_body}");
+      /* set as dir either remaining output path parts recombined or directory name,
+             as stem either all but last output filename part, stem set or src stem, and
+             as ext last output filename part */
+      let dir = if !parts_path.is_empty() { parts_path.join("/") } else { path_dir.to_string() };
+      let dir = match &config.dir_base {
+        Some(dir_base) => Path::new(dir_base).join(dir).display().to_string(),
+        None           => dir
+      };
+      let stem = if p_f_len > 1 {
+        parts_filename[..(p_f_len - 1)]
+          .join(".")
+      } else if let Some(ConfigReceiptVal::Strs(strs)) = config.receipts.get("stem") {
+        strs.last().cloned().unwrap_or_default()
+      } else {
+        /* take stem of source filename, or of default source where source on stdin */
+        let path_src = config.get_or("path_src", "path_src");
+        let path_src = if "-" == path_src { defaults.expect("path_src") } else { path_src.as_str() };
+        OutputFilePath::stem_from_path_get(path_src)
+      };
+      let ext = parts_filename
+        .iter()
+        .last()
+        .unwrap()
+        .to_string();
+
+      let path = OutputFilePath{ dir, stem, ext };
+
+      /* set output init parts */
+
+      /* set as items tag line data plus any command for output extension if command absent */
+      let items = match (data.len(), config.get_cmd(&path.ext)) {
+        (1, Some(cmd)) => [data.to_owned(), cmd.split_whitespace().map(String::from).collect()].concat(),
+        _              => data.to_owned()
+      };
+
+      /* handle file run precluded */
+      if items.len() == 1 {
+        let init = OutputFileInit::Text(
+          OutputText::Stderr(
+            format!("Not running file no. {n} (no values)")
+          )
+        );
+        return OutputFile { data, code, path, init, n };
+      }
+      let sig_stop = config.get_or("stop", "sig_stop");
+      if *items.get(1).unwrap() == sig_stop {
+        let init = OutputFileInit::Text(
+          OutputText::Stderr(
+            format!("Not running file no. {n} ({sig_stop} applied)")
+          )
+        );
+        return OutputFile { data, code, path, init, n };
+      }
+
+      /* set as parts of each command item text and any uses of output path placeholder,
+         noting presence of placeholder as indicator of composite command */
+      let plc_path_all = config.get_or("plc-all", "plc_path_all");
+      let (plc_head, plc_tail) = plc_path_all.split_once("{}").unwrap_or((plc_path_all.as_str(), ""));
+
+      let parts_items = items
+        .iter()
+        .skip(1)
+        .map(|item| OutputFileCmdPart::parse(item, plc_head, plc_tail, n))
+        .collect::<Vec<_>>();
+
+      let has_placeholder = parts_items
+        .iter()
+        .flatten()
+        .any(|part| matches!(part, OutputFileCmdPart::Path(..)));
+      /* take escaped placeholder head or tail as is without '\' only where any placeholder, i.e. composite command */
+      let texts_items = if has_placeholder {
+        parts_items
+          .iter()
+          .map(|parts| OutputFileCmdPart::text_get(parts))
+          .collect::<Vec<_>>()
+      } else {
+        items[1..].to_vec()
+      };
+
+      /* set as shell parts either tag line prefix, setting or default, with default flag if none */
+      let shell = shell.unwrap_or_else(|| config.get_or("shell", "cmd_prog"));
+      let mut shell_parts = shell
+        .split_whitespace()
+        .map(|part| part.to_owned())
+        .collect::<Vec<_>>();
+      if shell_parts.len() < 2 { shell_parts.push(defaults.expect("cmd_flag").to_string()) };
+
+      /* set as prog either second item or shell program, and
+             as args either Vec containing remaining items plus combined path or shell flags plus remaining items joined */
+      let prog = if has_placeholder { shell_parts.remove(0) } else { texts_items[0].to_owned() };
+      let args = if has_placeholder {
+        [
+          shell_parts,
+          Vec::from([
+            items
+              .iter()
+              .skip(1)
+              .map(|item| item.to_owned())
+              .collect::<Vec<_>>()
+              .join(" ")
+          ])
+        ]
+          .concat()
+      } else {
+        [
+          texts_items[1..].to_vec(),
+          Vec::from([path.get()])
+        ]
+          .concat()
+      };
+
+      /* set as parts for composite command those of each item, with space between */
+      let parts = if has_placeholder {
+        parts_items
+          .into_iter()
+          .enumerate()
+          .fold(Vec::new(), |mut acc, (i, parts)| {
+            if 0 != i { OutputFileCmdPart::push(&mut acc, OutputFileCmdPart::Text(String::from(" "))) };
+            parts.into_iter().for_each(|part| OutputFileCmdPart::push(&mut acc, part));
+            acc
+          })
+      } else {
+        Vec::new()
+      };
+
+      let init = OutputFileInit::Code(OutputFileInitCode { prog, args, parts });
+
+      OutputFile { data, code, path, init, n }
+    }
+
+    /* get reason file present is not to be overwritten where not forced, i.e. content other than script and
+       changed since last saved, with file never saved taken as overwritable, e.g. from before manifest */
+    fn conflict_get(&self, manifest: &OutputManifest) -> Option<String> {
+
+      if manifest.is_forced { return None };
+      let path = self.path.get();
+      let hash_saved = manifest.hashes.get(&OutputManifest::key_get(&path))?;
+      let hash = OutputManifest::hash_get(&fs::read(&path).ok()?);
+      if hash == *hash_saved || hash == OutputManifest::hash_get(self.code.as_bytes()) { return None };
+      Some(String::from("changed since last saved"))
+    }
+
+    fn check(&self, manifest: &OutputManifest) -> Result<(), Error> {
+      match self.conflict_get(manifest) {
+        Some(reason) => Err(Error::new(ErrorKind::Save, format!("Not saving file no. {} to '{}' ({reason}, use '--force' to overwrite)", self.n, self.path.get()))),
+        None         => Ok(())
+      }
+    }
+
+    fn save(&self, manifest: &mut OutputManifest) -> Result<(), Error> {
+
+      self.check(manifest)?;
+
+      let OutputFile { data: _, code, path, init: _, n } = self;
+      let dir = &path.dir;
+      let path = path.get();
+      let summary_failure = format!("Not saving file no. {n} to '{path}'");
+
+      /* handle file present and unchanged, so left as is */
+      let hash_code = OutputManifest::hash_get(code.as_bytes());
+      if fs::read(&path).is_ok_and(|content| hash_code == OutputManifest::hash_get(&content)) {
+        return manifest.record(&OutputManifest::key_get(&path), hash_code);
+      }
+
+      /* add directory if none */
+      fs::create_dir_all(dir)
+        .map_err(|e| Error::new(ErrorKind::Save, &summary_failure).with("create directory", e))?;
+      /* write script to file, noting hash */
+      fs::write(&path, code)
+        .map_err(|e| Error::new(ErrorKind::Save, &summary_failure).with("write", e))?;
+      manifest.record(&OutputManifest::key_get(&path), OutputManifest::hash_get(code.as_bytes()))
+    }
+
+    fn exec(&self, context: &HashMap<usize, String>) -> Result<(), Error> {
+
+      let OutputFile { data: _, code: _, path: _, init, n } = self;
+
+      match init {
+
+        /* print reason file run precluded */
+        OutputFileInit::Text(e) => e.print(),
+        /* run script from file */
+        OutputFileInit::Code(c) => {
+          let summary_failure = format!("Not running file no. {n} with {}", c.runner_get());
+
+          process::Command::new(&c.prog)
+            .args(c.args_full_get(context)?)
+            .spawn()
+            .map_err(|e| Error::new(ErrorKind::Exec, &summary_failure).with("spawn", e))?
+            .wait_with_output()
+            .map_err(|e| Error::new(ErrorKind::Exec, &summary_failure).with("wait", e))?;
+        }
+      }
+      Ok(())
+    }
+
+    fn preview(&self, context: &HashMap<usize, String>, manifest: &OutputManifest) -> Result<(), Error> {
+
+      let OutputFile { data: _, code: _, path, init, n } = self;
+
+      println!("{n}: save to '{}'", path.get());
+      if let Some(reason) = self.conflict_get(manifest) {
+        println!("{n}: not saving, file {reason}, use '--force' to overwrite");
+      }
+      match init {
+        OutputFileInit::Text(e) => {
+          match e {
+            OutputText::Stdout(s) => {  println!("{n}: {s}"); },
+            OutputText::Stderr(s) => { eprintln!("{n}: {s}"); }
+          }
+        },
+        OutputFileInit::Code(c) => {
+          let args = c.args_full_get(context)?;
+          let cmd = if c.parts.is_empty() {
+            [Vec::from([c.prog.to_owned()]), args].concat().join(" ")
+          } else {
+            args.last().cloned().unwrap_or_default()
+          };
+          println!("{n}: run with {}: {cmd}", c.runner_get());
+        }
+      }
+      Ok(())
+    }
+  }
+
+  #[derive(Debug, PartialEq)]
+  pub struct OutputFilePath {
+    pub dir:  String,
+    pub stem: String,
+    pub ext:  String
+  }
+
+  impl OutputFilePath {
+    pub fn get(&self) -> String {
+      format!("{}/{}.{}", &self.dir, &self.stem, &self.ext)
+    }
+
+    /* get stem from source path as filename to first period, skipping any leading */
+    pub fn stem_from_path_get(path_src: &str) -> String {
+      let name = Path::new(path_src)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+      let stem = name.trim_start_matches('.').split('.').next().unwrap_or_default();
+      if stem.is_empty() { name } else { stem.to_string() }
+    }
+  }
+
+  #[derive(Debug, PartialEq)]
+  pub enum OutputFileInit {
+    Text(OutputText),
+    Code(OutputFileInitCode)
+  }
+
+  #[derive(Debug, PartialEq)]
+  pub struct OutputFileInitCode {
+    pub prog:  String,
+    pub args:  Vec<String>,
+    pub parts: Vec<OutputFileCmdPart>
+  }
+
+  impl OutputFileInitCode {
+
+    /* get args with composite command, if any, as last arg, composed from parts with each placeholder replaced by output path */
+    fn args_full_get(&self, context: &HashMap<usize, String>) -> Result<Vec<String>, Error> {
+
+      let OutputFileInitCode { prog: _, args, parts } = self;
+
+      let Some((_, flags)) = args.split_last().filter(|_| !parts.is_empty()) else { return Ok(args.to_owned()) };
+      let cmd = parts
+        .iter()
+        .map(|part| match part {
+          OutputFileCmdPart::Text(text)   => Ok(text.as_str()),
+          OutputFileCmdPart::Path(n, plc) => context
+            .get(n)
+            .map(|path| path.as_str())
+            .ok_or_else(|| Error::new(ErrorKind::Exec, format!("Not running command with placeholder '{plc}' (no file no. {n})")))
+        })
+        .collect::<Result<String, Error>>()?;
+      Ok([flags.to_vec(), Vec::from([cmd])].concat())
+    }
+
+    /* get description of program run, as shell with flags if composite command */
+    fn runner_get(&self) -> String {
+      let OutputFileInitCode { prog, args, parts } = self;
+      if parts.is_empty() {
+        format!("'{prog}'")
+      } else {
+        let flags = args[..(args.len() - 1)].join(" ");
+        format!("shell '{}'", [prog.as_str(), flags.as_str()].join(" ").trim())
+      }
+    }
+  }
+
+  /* hash of content last saved per output path, by absolute path, with file kept in sync on each record */
+  pub struct OutputManifest {
+    pub path:      PathBuf,
+    pub hashes:    HashMap<String, u64>,
+    pub is_forced: bool
+  }
+
+  impl OutputManifest {
+
+    /* load from file, with each line hash in hex then tab then path, or as empty where file absent */
+    pub fn load(path: PathBuf, is_forced: bool) -> Result<OutputManifest, Error> {
+      let text = match fs::read_to_string(&path) {
+        Ok(text)                                      => text,
+        Err(e) if io::ErrorKind::NotFound == e.kind() => String::new(),
+        Err(e)                                        => {
+          let summary = format!("Not loading output manifest '{}'", path.display());
+          return Err(Error::new(ErrorKind::Save, summary).with("read", e));
+        }
+      };
+      let hashes = text
+        .lines()
+        .filter_map(|line| {
+          let (hash, key) = line.split_once('\t')?;
+          Some((key.to_string(), u64::from_str_radix(hash, 16).ok()?))
+        })
+        .collect::<HashMap<_, _>>();
+      Ok(OutputManifest { path, hashes, is_forced })
+    }
+
+    /* set hash for key, writing file only where hash new */
+    pub fn record(&mut self, key: &str, hash: u64) -> Result<(), Error> {
+
+      if self.hashes.get(key) == Some(&hash) { return Ok(()) };
+      self.hashes.insert(key.to_string(), hash);
+
+      let summary_failure = format!("Not updating output manifest '{}'", self.path.display());
+      let mut entries = self.hashes.iter().collect::<Vec<_>>();
+      entries.sort();
+      let text = entries
+        .into_iter()
+        .map(|(key, hash)| format!("{hash:016x}\t{key}\n"))
+        .collect::<String>();
+
+      if let Some(dir) = self.path.parent() {
+        fs::create_dir_all(dir)
+          .map_err(|e| Error::new(ErrorKind::Save, &summary_failure).with("create directory", e))?;
+      }
+      fs::write(&self.path, text)
+        .map_err(|e| Error::new(ErrorKind::Save, &summary_failure).with("write", e))
+    }
+
+    /* get key for output path as absolute path, so independent of directory run from */
+    pub fn key_get(path: &str) -> String {
+      fs::canonicalize(path)
+        .unwrap_or_else(|_| PathBuf::from(path))
+        .display()
+        .to_string()
+    }
+
+    /* get 64-bit FNV-1a hash of bytes */
+    pub fn hash_get(bytes: &[u8]) -> u64 {
+      bytes
+        .iter()
+        .fold(0xcbf29ce484222325, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3))
+    }
+  }
+
+  /* part of command item, as text or as placeholder for output path of script no., with placeholder as written */
+  #[derive(Debug, PartialEq)]
+  pub enum OutputFileCmdPart {
+    Text(String),
+    Path(usize, String)
+  }
+
+  impl OutputFileCmdPart {
+
+    /* get parts of item, taking as placeholder any placeholder head and tail enclosing either nothing, for current script no. (n),
+       or digits only, for script no. given, and as text any other content, with any head or tail preceded by '\' as is */
+    pub fn parse(item: &str, plc_head: &str, plc_tail: &str, n: usize) -> Vec<OutputFileCmdPart> {
+
+      let mut parts = Vec::new();
+      let mut rest = item;
+      while let Some(c) = rest.chars().next() {
+
+        /* handle escaped head or tail */
+        let escaped = rest
+          .strip_prefix('\\')
+          .and_then(|r| [plc_head, plc_tail].into_iter().find(|s| !s.is_empty() && r.starts_with(s)));
+        if let Some(s) = escaped {
+          OutputFileCmdPart::push(&mut parts, OutputFileCmdPart::Text(s.to_string()));
+          rest = &rest[(1 + s.len())..];
+          continue;
+        }
+
+        /* handle placeholder, with script no. taken to end of digits where no tail */
+        if let Some(r) = rest.strip_prefix(plc_head) {
+          let content_and_rest = if plc_tail.is_empty() {
+            Some(r.split_at(r.find(|c: char| !c.is_ascii_digit()).unwrap_or(r.len())))
+          } else {
+            r.split_once(plc_tail)
+          };
+          if let Some((content, r)) = content_and_rest.filter(|(content, _)| content.chars().all(|c| c.is_ascii_digit())) {
+            let n_plc = if content.is_empty() { n } else { content.parse::<usize>().unwrap_or(usize::MAX) };
+            parts.push(OutputFileCmdPart::Path(n_plc, format!("{plc_head}{content}{plc_tail}")));
+            rest = r;
+            continue;
+          }
+        }
+
+        OutputFileCmdPart::push(&mut parts, OutputFileCmdPart::Text(c.to_string()));
+        rest = &rest[c.len_utf8()..];
+      }
+      parts
+    }
+
+    /* add part, extending any text last */
+    pub fn push(parts: &mut Vec<OutputFileCmdPart>, part: OutputFileCmdPart) {
+      match (parts.last_mut(), part) {
+        (Some(OutputFileCmdPart::Text(text)), OutputFileCmdPart::Text(s)) => text.push_str(&s),
+        (_, part)                                                          => parts.push(part)
+      }
+    }
+
+    /* get parts as text, with each placeholder as written */
+    pub fn text_get(parts: &[OutputFileCmdPart]) -> String {
+      parts
+        .iter()
+        .map(|part| match part {
+          OutputFileCmdPart::Text(text)   => text.as_str(),
+          OutputFileCmdPart::Path(_, plc) => plc.as_str()
+        })
+        .collect()
+    }
+  }
+}
+
+/* CONFIG, incl. argument_handling */
+
+mod config {
+
+  /* - imports */
+
+  use std::io::{self, Write};
+  use std::time::SystemTime;
+  use std::env;
+  use std::process;
+  use std::collections::HashMap;
+
+  use crate::error::{
+    Error,
+    ErrorKind
+  };
+
+  /* - data structures */
+
+  pub struct Config<'a> {
+    pub defaults: ConfigDefaults<'a>,
+    pub settings: ConfigSettings,
+    pub receipts: ConfigReceipts,
+    pub messages: ConfigMessages<'a>,
+    pub text_src: Option<String>, /* source text read from stdin, for source path '-' */
+    pub dir_base: Option<String>  /* base of relative output paths, where directory of source */
+  }
+
+  impl Config<'_> {
+
+    pub fn receive(mut config: Config<'static>, handle_remaining: &ArgHandler, args: Vec<String>) -> Result<Config<'static>, Error> {
+
+      /* for each flag in args, queue setting index with any values, collecting positional args and unknown flags */
+      let mut settings_queued = Vec::new();
+      let mut args_positional = Vec::new();
+      let mut args_unknown = Vec::new();
+      let mut args_iter = args.into_iter();
+      while let Some(arg) = args_iter.next() {
+
+        /* handle terminator, positional args incl. '-' and unknown flags */
+        if "--" == arg {
+          args_positional.extend(args_iter.by_ref());
+          break;
+        }
+        if !arg.starts_with('-') || "-" == arg {
+          args_positional.push(arg);
+          continue;
+        }
+        let Some(flags) = config.flags_parse(&arg) else {
+          args_unknown.push(arg);
+          continue;
+        };
+
+        /* queue each flag, taking any values first from any inline value then from args following */
+        for (i, val_inline) in flags {
+          let setting = &config.settings[i];
+          let name = setting.name_get(&arg);
+          let strs_len = setting.strs.len();
+          if 0 == strs_len && val_inline.is_some() {
+            return Err(Error::new(ErrorKind::Usage, format!("Not parsing option '{name}' (no value taken)")));
+          }
+          let strs = val_inline
+            .into_iter()
+            .chain(args_iter.by_ref().take(strs_len))
+            .take(strs_len)
+            .collect::<Vec<_>>();
+          if strs.len() < strs_len {
+            let strs_missing = setting.strs[strs.len()..].join(" ");
+            return Err(Error::new(ErrorKind::Usage, format!("Not parsing option '{name}' (missing {strs_missing})")));
+          }
+          if !setting.is_repeatable && settings_queued.iter().any(|(j, _)| i == *j) {
+            return Err(Error::new(ErrorKind::Usage, format!("Not parsing option '{name}' (repeated, not repeatable)")));
+          }
+          settings_queued.push((i, strs));
+        }
+      }
+      /* handle positional args and any unknown flags */
+      handle_remaining(&mut config, args_positional, args_unknown)?;
+
+      /* make any queued setting calls, skipping settings received for earlier args, for precedence,
+         other than those repeatable taking string values, i.e. keyed, the values for which are extended,
+         with values for earlier args placed last, for precedence */
+      let words_prior = config.receipts.keys().cloned().collect::<Vec<_>>();
+      settings_queued.sort_by_key(|(i, _)| *i);
+      for (i, strs) in settings_queued {
+        let word = config.settings[i].word.to_owned();
+        let is_prior = words_prior.contains(&word);
+        let is_keyed = config.settings[i].is_repeatable && matches!(config.receipts.get(&word), Some(ConfigReceiptVal::Strs(_)));
+        if is_prior && !is_keyed { continue };
+        let value = match ((config.settings[i].call)(&config, strs)?, config.receipts.remove(&word)) {
+          (ConfigReceiptVal::Strs(vals), Some(ConfigReceiptVal::Strs(vals_other))) if is_prior => ConfigReceiptVal::Strs([vals, vals_other].concat()),
+          (ConfigReceiptVal::Strs(vals), Some(ConfigReceiptVal::Strs(vals_other))) => ConfigReceiptVal::Strs([vals_other, vals].concat()),
+          (ConfigReceiptVal::Ints(vals), Some(ConfigReceiptVal::Ints(vals_other))) => ConfigReceiptVal::Ints([vals_other, vals].concat()),
+          (value, _)                                                               => value
+        };
+        config.receipts.insert(word, value);
+      }
+      Ok(config)
+    }
+
+    /* get for flag arg each setting index with any inline value, for single long flag with any '=' value
+       or short flag cluster with any remainder after flag taking values, or none if any flag unknown */
+    pub fn flags_parse(&self, arg: &str) -> Option<Vec<(usize, Option<String>)>> {
+
+      if let Some(word) = arg.strip_prefix("--") {
+        let (word, val) = match word.split_once('=') {
+          Some((word, val)) => (word, Some(val.to_string())),
+          None              => (word, None)
+        };
+        let i = self.settings.iter().position(|setting| word == setting.word)?;
+        return Some(Vec::from([(i, val)]));
+      }
+
+      let chars = arg.strip_prefix('-')?;
+      let mut flags = Vec::new();
+      for (j, char) in chars.char_indices() {
+        let i = self.settings.iter().position(|setting| setting.char.chars().eq([char]))?;
+        let rest = &chars[(j + char.len_utf8())..];
+        if !self.settings[i].strs.is_empty() && !rest.is_empty() {
+          flags.push((i, Some(rest.to_string())));
+          break;
+        }
+        flags.push((i, None));
+      }
+      Some(flags)
+    }
+
+    pub fn get_cmd(&self, ext: &str) -> Option<String> {
+      /* get command for extension from last pair received, else from defaults */
+      if let Some(ConfigReceiptVal::Strs(val_strs)) = self.receipts.get("cmd") {
+        if let Some(pair) = val_strs.chunks(2).rev().find(|pair| ext == pair[0]) {
+          return pair.get(1).map(String::from);
+        }
+      }
+      self.defaults.commands.get(ext).map(String::from)
+    }
+
+    pub fn get_or(&self, key_receipt: &str, key_default: &str) -> String {
+      if let Some(ConfigReceiptVal::Strs(val_strs)) = self.receipts.get(key_receipt) {
+        if let Some(val) = val_strs.first() { return val.to_string() };
+      }
+      String::from(self.defaults.expect(key_default))
+    }
+  }
+
+  pub struct ConfigDefaults<'a> {
+    pub repository: HashMap<&'a str, String>,
+    pub commands:   HashMap<String, String>,
+    pub origins:    HashMap<String, String>
+  }
+
+  impl<'a> ConfigDefaults<'a> {
+
+    pub fn from<T, U>(defaults: T, commands: U) -> Self
+    where
+      T: IntoIterator<Item=(&'a str, &'a str)>,
+      U: IntoIterator<Item=(&'a str, &'a str)>
+    {
+      Self {
+        repository: defaults
+          .into_iter()
+          .map(|(key, val)| (key, String::from(val)))
+          .collect(),
+        commands: commands
+          .into_iter()
+          .map(|(ext, cmd)| (String::from(ext), String::from(cmd)))
+          .collect(),
+        origins: HashMap::new()
+      }
+    }
+
+    pub fn expect(&self, key: &str) -> &str {
+      self.repository
+        .get(key)
+        .unwrap_or_else(|| panic!("get default value '{key}'"))
+    }
+
+    /* returns false if no default for key */
+    pub fn update(&mut self, key: &str, val: &str) -> bool {
+      match self.repository.get_mut(key) {
+        Some(v) => { *v = String::from(val); true },
+        None    => false
+      }
+    }
+  }
+
+  pub type ConfigSettings = Vec<ConfigSetting>;
+  pub type ConfigReceipts = HashMap<String, ConfigReceiptVal>;
+
+  #[derive(PartialEq, Eq)]
+  pub enum ConfigReceiptVal {
+    Bool,
+    Ints(Vec<usize>),
+    Strs(Vec<String>)
+  }
+
+  pub struct ConfigMessages<'a> {
+    pub repository: HashMap<&'a str, String>,
+    pub keys_notes: Vec<&'a str>
+  }
+
+  impl ConfigMessages<'_> {
+
+    pub fn expect(&self, key: &str) -> &str {
+      self.repository
+        .get(key)
+        .unwrap_or_else(|| panic!("get message '{key}'"))
+    }
+
+    pub fn compose_notes(&self) -> Vec<String> {
+      self.keys_notes
+        .iter()
+        .map(|k|
+          self.repository
+            .get(k)
+            .unwrap_or_else(|| panic!("get message '{k}' from configuration for notes"))
+            .to_string()
+        )
+        .collect()
+    }
+  }
+
+  type ConfigSettingCall = dyn Fn(&Config, Vec<String>) -> Result<ConfigReceiptVal, Error>;
+
+  pub struct ConfigSetting {
+    pub word: String,
+    pub char: String,
+    pub strs: Vec<String>,
+    pub desc: String,
+    pub kinds: Vec<String>,
+    pub is_repeatable: bool,
+    pub is_cli_only: bool,
+        call: Box<ConfigSettingCall>
+  }
+
+  impl ConfigSetting {
+    pub fn new(word: &str, char: &str, val_strs: &[&str], desc: &str, call: &'static ConfigSettingCall) -> ConfigSetting {
+      let strs = if !val_strs.is_empty() {
+        val_strs
+          .iter()
+          .map(|&s| String::from(s))
+          .collect::<Vec<_>>()
+      } else {
+        Vec::new()
+      };
+      ConfigSetting {
+        word: String::from(word),
+        char: String::from(char),
+        strs,
+        desc: String::from(desc),
+        kinds: vec![String::from("none"); val_strs.len()],
+        is_repeatable: false,
+        is_cli_only: false,
+        call: Box::new(call)
+      }
+    }
+    /* set kind of each value taken, for completions, i.e. 'scripts', 'file', 'dir', 'kind', 'mode', 'base' or 'none' */
+    pub fn completing(mut self, kinds: &[&str]) -> ConfigSetting {
+      self.kinds = kinds.iter().map(|&kind| String::from(kind)).collect::<Vec<_>>();
+      self
+    }
+    /* set as refused in source preface, e.g. where acting once then exiting */
+    pub fn cli_only(mut self) -> ConfigSetting {
+      self.is_cli_only = true;
+      self
+    }
+    /* set as taking values from repeated flags, combined */
+    pub fn repeatable(mut self) -> ConfigSetting {
+      self.is_repeatable = true;
+      self
+    }
+    /* get name for flag as used in arg, long or short */
+    pub fn name_get(&self, arg: &str) -> String {
+      if arg.starts_with("--") { format!("--{}", self.word) } else { format!("-{}", self.char) }
+    }
+    pub fn new_version() -> ConfigSetting {
+      ConfigSetting::new("version", "v", &[], "show name and version number then exit", &setting_version_apply).cli_only()
+    }
+    pub fn new_help() -> ConfigSetting {
+      ConfigSetting::new("help", "h", &[], "show usage, flags available and notes then exit", &setting_help_apply).cli_only()
+    }
+    pub fn new_man() -> ConfigSetting {
+      ConfigSetting::new("man", "M", &[], "print the man page in roff format, with usage, flags and notes as for --help, then exit", &setting_man_apply).cli_only()
+    }
+  }
+
+  /* handler for positional args and unknown flags */
+  type ArgHandler = dyn Fn(&mut Config, Vec<String>, Vec<String>) -> Result<(), Error>;
+
+  /* - argument applicator ('help') */
+
+  fn setting_version_apply(_: &Config, _: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+    println!("{}", name_and_version_get());
+    process::exit(0);
+  }
+
+  fn setting_help_apply(config: &Config, _: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+
+    let line_length_max = 80;
+
+    /* set value substrings and max length */
+    let strs_strs = config.settings
+      .iter()
+      .map(|o| o.strs.join(" "))
+      .collect::<Vec<_>>();
+    let strs_strs_max = strs_strs.iter()
+      .fold(0, |acc, s| if s.len() > acc { s.len() } else { acc });
+    let flag_strs = config.settings
+      .iter()
+      .map(|o| format!("-{}, --{}", o.char, o.word))
+      .collect::<Vec<_>>();
+    let flag_strs_max = flag_strs
+      .iter()
+      .fold(0, |acc, s| if s.len() > acc { s.len() } else { acc });
+
+    /* generate title line */
+    let title_line = line_center_with_fill(&name_and_version_get(), line_length_max, "-");
+
+    /* generate usage text */
+    let usage_opts_part = usage_settings_get(config)
+      .map(|o| format!(
+        "[--{}/-{}{}]",
+        o.word,
+        o.char,
+        if o.strs.is_empty() { String::from("") } else { [" ", &o.strs.join(" ")].concat() })
+      )
+      /* set spaces within each option to no-break space, to avoid break within */
+      .map(|s| s.replace(' ', "\u{a0}"))
+      .collect::<Vec<_>>()
+      .join(" ");
+    let usage_opts_head = line_break_and_indent(&format!("{usage_opts_part} [SOURCE]"), 15, line_length_max, false)
+      .replace('\u{a0}', " ");
+    let usage_opts_tail = line_break_and_indent("/ --version/-v / --help/-h", 15, line_length_max, true);
+    let usage_text = format!("Usage: aliesce {usage_opts_head}\n{usage_opts_tail}");
+
+    /* generate flags text */
+    let flags_list = config.settings
+      .iter()
+      .enumerate() /* yield also index (i) */
+      .map(|(i, o)| {
+        let desc = line_break_and_indent(&o.desc, flag_strs_max + strs_strs_max + 2, line_length_max, false);
+        format!(" {}  {:w$}  {desc}", flag_strs[i], strs_strs[i], w = flag_strs_max - o.word.len())
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
+    let flags_text = format!("Flags:\n{flags_list}");
+
+    /* generate notes text */
+    let notes_body = config.messages.compose_notes()
+      .iter()
+      .map(|l| line_break_and_indent(l, 1, line_length_max, true))
+      .collect::<Vec<_>>()
+      .join("\n\n");
+    let notes_text = format!("Notes:\n{notes_body}");
+
+    println!("{title_line}\n\n{usage_text}\n{flags_text}\n\n{notes_text}");
+    process::exit(0);
+  }
+
+  fn setting_man_apply(config: &Config, _: Vec<String>) -> Result<ConfigReceiptVal, Error> {
+
+    let name = env!("CARGO_PKG_NAME");
+
+    /* generate synopsis text */
+    let synopsis_opts = usage_settings_get(config)
+      .map(|o| {
+        let strs = o.strs.iter().map(|s| format!(" \\fI{}\\fR", roff_escape(s))).collect::<String>();
+        format!("[\\fB\\-\\-{}\\fR/\\fB\\-{}\\fR{strs}]", roff_escape(&o.word), roff_escape(&o.char))
+      })
+      .collect::<Vec<_>>()
+      .join(" ");
+    let synopsis_text = format!(".SH SYNOPSIS\n.B {name}\n{synopsis_opts} [\\fISOURCE\\fR]\n.br\n.B {name}\n\\fB\\-\\-version\\fR/\\fB\\-v\\fR | \\fB\\-\\-help\\fR/\\fB\\-h\\fR");
+
+    /* generate options text */
+    let options_list = config.settings
+      .iter()
+      .map(|o| {
+        let strs = o.strs.iter().map(|s| format!(" \\fI{}\\fR", roff_escape(s))).collect::<String>();
+        format!(".TP\n\\fB\\-{}\\fR, \\fB\\-\\-{}\\fR{strs}\n{}", roff_escape(&o.char), roff_escape(&o.word), roff_escape(&o.desc))
+      })
+      .collect::<Vec<_>>()
+      .join("\n");
+    let options_text = format!(".SH OPTIONS\n{options_list}");
+
+    /* generate notes text */
+    let notes_body = config.messages.compose_notes()
+      .iter()
+      .map(|l| format!(".PP\n{}", roff_escape(l)))
+      .collect::<Vec<_>>()
+      .join("\n");
+    let notes_text = format!(".SH NOTES\n{notes_body}");
+
+    let text = format!(
+      ".TH {} 1 \"{}\" \"{}\" \"User Commands\"\n.SH NAME\n{name} \\- {}\n{synopsis_text}\n{options_text}\n{notes_text}\n",
+      name.to_uppercase(),
+      date_get(),
+      name_and_version_get(),
+      roff_escape(env!("CARGO_PKG_DESCRIPTION"))
+    );
+
+    /* print via locked stdout, ending without error where closed early, e.g. piped to 'head' */
+    let mut stdout = io::stdout().lock();
+    match stdout.write_all(text.as_bytes()).and_then(|_| stdout.flush()) {
+      Err(e) if io::ErrorKind::BrokenPipe != e.kind() => Err(Error::new(ErrorKind::Save, "Not printing man page").with("write", e)),
+      _                                               => process::exit(0)
+    }
+  }
+
+  /* - utility functions */
+
+  /* get date as YYYY-MM-DD from any SOURCE_DATE_EPOCH, for reproducible output, or else current time */
+  fn date_get() -> String {
+    let secs = env::var("SOURCE_DATE_EPOCH")
+      .ok()
+      .and_then(|val| val.parse::<u64>().ok())
+      .unwrap_or_else(|| {
+        SystemTime::now()
+          .duration_since(SystemTime::UNIX_EPOCH)
+          .map(|duration| duration.as_secs())
+          .unwrap_or_default()
+      });
+    date_from_days_get(secs / 86400)
+  }
+
+  /* get civil date for days since Unix epoch, per the proleptic Gregorian calendar */
+  pub fn date_from_days_get(days: u64) -> String {
+    let z = days + 719468;
+    let era = z / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + u64::from(m <= 2);
+    format!("{y:04}-{m:02}-{d:02}")
+  }
+
+  /* get settings for usage, avoiding duplication of version and help */
+  fn usage_settings_get<'a>(config: &'a Config) -> impl Iterator<Item = &'a ConfigSetting> {
+    config.settings
+      .iter()
+      .filter(|o| o.word != "version" && o.word != "help")
+  }
+
+  fn roff_escape(text: &str) -> String {
+    let text = text
+      .replace('\\', "\\e")
+      .replace('-', "\\-");
+    /* avoid control line for text begun with period or apostrophe */
+    if text.starts_with(['.', '\'']) { format!("\\&{text}") } else { text }
+  }
+
+  fn name_and_version_get() -> String {
+    format!("{} v{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+  }
+
+  fn line_center_with_fill(line: &str, length: usize, fill: &str) -> String {
+    let line_len = line.chars().count();
+    let whitespace_half = String::from(fill).repeat((length - line_len - 2) / 2);
+    let whitespace_last = if line_len.is_multiple_of(2) { "" } else { fill };
+    format!("{whitespace_half} {line} {whitespace_half}{whitespace_last}")
+  }
+
+  fn line_break_and_indent(line: &str, indent: usize, length: usize, indent_first: bool ) -> String {
+
+    let whitespace_part = String::from(" ").repeat(indent);
+    let whitespace_full = format!("\n{whitespace_part}");
+    let text_width = length - indent;
+
+    let body = line
+      .split(' ')
+      .collect::<Vec<_>>()
+      .iter()
+      .fold(Vec::new(), |mut acc: Vec<String>, word| {
+        if acc.is_empty() { return Vec::from([String::from(*word)]) };
+        /* accrue text part of each line by word, not exceeding text width */
+        let index_last = acc.len() - 1;
+        match acc[index_last].chars().count() + word.chars().count() >= text_width {
+          /* begin new text part with word */
+          true => acc.push(String::from(*word)),
+          /* add word to current text part */
+          _    => acc[index_last].push_str(&format!(" {}", *word))
+        };
+        acc
+      })
+      .join(whitespace_full.as_str());
+
+    if indent_first { format!("{whitespace_part}{body}") } else { body }
+  }
+}
+
+/* ERROR */
+
+mod error {
+
+  /* - imports */
+
+  use std::io;
+  use std::fmt;
+
+  /* - data structures */
+
+  /* failure class, each with distinct exit code */
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum ErrorKind {
+    Usage,
+    Config,
+    Source,
+    Lock,
+    Save,
+    Exec
+  }
+
+  impl ErrorKind {
+    pub fn code(&self) -> i32 {
+      match self {
+        ErrorKind::Usage  => 2,
+        ErrorKind::Config => 3,
+        ErrorKind::Source => 4,
+        ErrorKind::Lock   => 5,
+        ErrorKind::Save   => 6,
+        ErrorKind::Exec   => 7
+      }
+    }
+  }
+
+  #[derive(Debug)]
+  pub struct Error {
+    pub kind:     ErrorKind,
+    pub summary:  String,
+    pub cause:    Option<(String, io::Error)>,
+    pub location: Option<Box<Location>>
+  }
+
+  impl Error {
+
+    pub fn new(kind: ErrorKind, summary: impl Into<String>) -> Error {
+      Error { kind, summary: summary.into(), cause: None, location: None }
+    }
+
+    /* add action attempted and io error arising */
+    pub fn with(mut self, action: &str, e: io::Error) -> Error {
+      self.cause = Some((String::from(action), e));
+      self
+    }
+
+    /* add location in file giving rise to error */
+    pub fn at(mut self, location: Location) -> Error {
+      self.location = Some(Box::new(location));
+      self
+    }
+  }
+
+  impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      let summary = match &self.cause {
+        Some((a, e)) => format!("{} ({a} error: '{e}')", self.summary),
+        None         => self.summary.to_owned()
+      };
+      match &self.location {
+        Some(location) => write!(f, "{}", location.describe(&summary)),
+        None           => write!(f, "{summary}")
+      }
+    }
+  }
+
+  /* line and column in file, each from 1, with text of line */
+  #[derive(Debug, Clone, PartialEq, Eq)]
+  pub struct Location {
+    pub path:   String,
+    pub line_n: usize,
+    pub col_n:  usize,
+    pub text:   String
+  }
+
+  impl Location {
+
+    /* get summary preceded by path, line and column, as for editor navigation, and followed by line marked at column */
+    pub fn describe(&self, summary: &str) -> String {
+      let Location { path, line_n, col_n, text } = self;
+      let indent = text
+        .chars()
+        .take(col_n.saturating_sub(1))
+        .map(|c| if '\t' == c { '\t' } else { ' ' })
+        .collect::<String>();
+      format!("{path}:{line_n}:{col_n}: {summary}\n  {text}\n  {indent}^")
+    }
+  }
+}
+
+/* TEST */
+
+#[cfg(test)]
+mod test {
+
+  /* - imports */
+
+  use::std::io::Write;
+  use::std::fs;
+  use::std::process;
+  use::std::path::{Path, PathBuf};
+  use::std::collections::HashMap;
+
+  use super::{
+    DEFAULTS,
+    COMMANDS,
+    PATH_CFG_USER,
+    PATH_CFG_PROJECT,
+    Script,
+    settings_new,
+    messages_new,
+    inputs_parse,
+    tag_head_add,
+    preface_line_split,
+    stdin_paths_get,
+    path_from_bytes
+  };
+  use crate::output::{
+    Output,
+    OutputText,
+    OutputFile,
+    OutputFilePath,
+    OutputFileInit,
+    OutputFileInitCode,
+    OutputFileCmdPart,
+    OutputManifest
+  };
+  use crate::config::{
+    Config,
+    ConfigDefaults,
+    ConfigReceiptVal,
+    date_from_days_get
+  };
+
+  /* - test cases */
+
+  /*   - end-to-end */
+
+  /*     - stdin read, settings */
+
+  const PATH_TMP_DIR_TEST: &str = "./.test_temp";
+
+  fn test_values_script_get(path_dir: &String, n: u8) -> (String, String, String, String, String) {
+    let output_filename = format!("test_{n}.sh");
+    let string = format!("Running {n}");
+    let output = format!("{string}\n");
+    (
+      format!("{path_dir}/script_{n}.txt"),
+      format!(">/{output_filename} sh"),
+      format!("echo \"{string}\"\n"),
+      output_filename,
+      output
+    )
+  }
+
+  fn test_values_end_to_end_get() -> [String; 23] {
+
+    let path_dir = String::from(PATH_TMP_DIR_TEST);
+    let path_dir_scripts = format!("{path_dir}/scripts");
+    let path_source      = format!("{path_dir}/source.txt");
+
+    let (
+      path_script_1, content_script_line_base_1, content_script_body_1,
+      content_script_output_filename_1, content_script_output_1
+    ) = test_values_script_get(&path_dir, 1);
+    let (
+      path_script_2, content_script_line_base_2, content_script_body_2,
+      content_script_output_filename_2, content_script_output_2
+    ) = test_values_script_get(&path_dir, 2);
+    let (
+      path_script_3, _,                          content_script_body_3,
+       _,                                content_script_output_3
+    ) = test_values_script_get(&path_dir, 3);
+
+    let content_source_preface = String::from("# Test preface\n");
+    let content_source_script_line = format!("{} sh sh\n", DEFAULTS[3].1);
+    let content_source_script_body = "echo \"Running initial\"\n".to_string();
+
+    let content_source_single = format!("{content_source_preface}{content_source_script_line}{content_source_script_body}");
+
+    let content_script_line_label = "Test label".to_string();
+
+    let content_script_line_tagged          = format!("{} {content_script_line_base_1}", DEFAULTS[3].1);
+    let content_script_line_tagged_labelled = format!("{} {content_script_line_label} {} {content_script_line_base_2}", DEFAULTS[3].1, DEFAULTS[4].1);
+    let content_script_line_tagged_bypass   = format!("{} {}", DEFAULTS[3].1, DEFAULTS[5].1);
+
+    let content_source_triple = format!("{content_source_preface}{content_script_line_tagged}\n{content_script_body_1}{content_script_line_tagged_labelled}\n{content_script_body_2}{content_script_line_tagged_bypass}\n{content_script_body_3}");
+
+    [
+      path_dir, path_dir_scripts, path_source, path_script_1, path_script_2, path_script_3,
+      content_script_output_filename_1, content_script_output_filename_2,
+      content_source_preface, content_source_script_body, content_source_single, content_source_triple,
+      content_script_line_base_1, content_script_line_base_2, content_script_line_tagged, content_script_line_tagged_bypass, content_script_line_label,
+      content_script_body_1, content_script_body_2, content_script_body_3,
+      content_script_output_1, content_script_output_2, content_script_output_3
+    ]
+  }
+
+  /* get command to run aliesce via cargo, with user configuration directory in test directory, so no user file loaded */
+  fn test_command_get() -> process::Command {
+    let mut command = process::Command::new("cargo");
+    command.env("XDG_CONFIG_HOME", format!("{PATH_TMP_DIR_TEST}/config"));
+    command
+  }
+
+  fn test_tree_create(files: Vec<[&str; 3]>) {
+    let path_dir = &test_values_end_to_end_get()[0];
+    fs::create_dir_all(path_dir)
+      .unwrap_or_else(|_| panic!("create temporary test directory '{path_dir}'"));
+    for file in files {
+      let [path_file, content_file, description] = file;
+      fs::write(path_file, content_file).unwrap_or_else(|_| panic!("write {description} to '{path_file}'"));
+    }
+  }
+
+  fn test_tree_remove() {
+    let path_dir = &test_values_end_to_end_get()[0];
+    fs::remove_dir_all(path_dir)
+      .unwrap_or_else(|_| panic!("remove temporary test directory '{path_dir}'"));
+  }
+
+  /*     - stdin read */
+
+  fn test_stdin_read_run(input_delimiter: &str, is_redirected: bool) {
+
+    let [
+      path_dir, _, path_source, path_script_1, path_script_2, path_script_3,
+      _, _,
+      _, _, content_source_single, _,
+      _, _, _, content_script_line_tagged_bypass, _,
+      content_script_body_1, content_script_body_2, content_script_body_3,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source,   &content_source_single, "test source"       ],
+      [&path_script_1, &content_script_body_1, "test script 1 body"],
+      [&path_script_2, &content_script_body_2, "test script 2 body"],
+      [&path_script_3, &content_script_body_3, "test script 3 body"]
+    ]));
+
+    let input = format!("{path_script_1}{d}{path_script_2}{d}{path_script_3}", d = input_delimiter);
+
+    /* acquisitions */
+
+    let output_raw = if is_redirected {
+      let path_input = format!("{path_dir}/paths.txt");
+      fs::write(&path_input, &input).unwrap();
+      test_command_get()
+        .args(Vec::from(["run", "--", &path_source]))
+        .stdin(fs::File::open(&path_input).unwrap())
+        .output()
+        .unwrap()
+    } else {
+      let mut proc = test_command_get()
+        .args(Vec::from(["run", "--", &path_source]))
+        .stdin(process::Stdio::piped())
+        .stdout(process::Stdio::piped())
+        .stderr(process::Stdio::piped())
+        .spawn()
+        .unwrap();
+
+      proc.stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+      proc
+        .wait_with_output()
+        .unwrap()
+    };
+
+    let output = String::from_utf8_lossy(&output_raw.stdout);
+    let source = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+    let source_line_1 = source.lines().nth( 4).unwrap();
+    let source_line_2 = source.lines().nth( 8).unwrap();
+    let source_line_3 = source.lines().nth(12).unwrap();
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert!(output.contains(&content_script_line_tagged_bypass));
+    assert!(output.contains(&path_script_1));
+    assert!(output.contains(&path_script_2));
+    assert!(output.contains(&path_script_3));
+
+    assert!(source.contains(&content_source_single));
+    assert_eq!(content_script_line_tagged_bypass, source_line_1);
+    assert_eq!(content_script_line_tagged_bypass, source_line_2);
+    assert_eq!(content_script_line_tagged_bypass, source_line_3);
+    assert!(source.contains(&content_script_body_1));
+    assert!(source.contains(&content_script_body_2));
+    assert!(source.contains(&content_script_body_3));
+  }
+
+  #[test]
+  fn stdin_read() {
+
+    let input_delimiter_1 = " ";
+    let input_delimiter_2 = "\n";
+    let input_delimiter_3 = "\r\n";
+    let input_delimiter_4 = "\0";
+
+    test_stdin_read_run(input_delimiter_1, false);
+    test_stdin_read_run(input_delimiter_2, false);
+    test_stdin_read_run(input_delimiter_3, false);
+    test_stdin_read_run(input_delimiter_4, false);
+
+    test_stdin_read_run(input_delimiter_2, true);
+    test_stdin_read_run(input_delimiter_4, true);
+  }
+
+  /*     - settings */
+
+  #[test]
+  fn setting_dest() {
+
+    let [
+      _, path_dir_scripts, path_source, _, _, _,
+      content_script_output_filename_1, content_script_output_filename_2,
+      _, _, _, content_source_triple,
+      _, _, _, _, _,
+      content_script_body_1, content_script_body_2, _,
+      content_script_output_1, content_script_output_2, _
+    ] = test_values_end_to_end_get();
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_triple, "test source"]
+    ]));
+
+    /* acquisitions */
+
+    let output_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-d", &path_dir_scripts, &path_source]))
+      .output()
+      .unwrap();
+
+    let output = String::from_utf8_lossy(&output_raw.stdout);
+
+    let scripts = fs::read_dir(&path_dir_scripts).unwrap()
+      .map(|e| e.unwrap().path().display().to_string())
+      .collect::<Vec<_>>();
+    let scripts_path_1 = format!("{path_dir_scripts}/{content_script_output_filename_1}");
+    let scripts_path_2 = format!("{path_dir_scripts}/{content_script_output_filename_2}");
+    let scripts_body_1 = fs::read_to_string(&scripts_path_1).unwrap();
+    let scripts_body_2 = fs::read_to_string(&scripts_path_2).unwrap();
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert_eq!(output.to_string(), format!("{content_script_output_1}{content_script_output_2}"));
+
+    assert_eq!(scripts.len(), 2);
+    assert!(scripts.contains(&scripts_path_1));
+    assert!(content_script_body_1.contains(&scripts_body_1));
+    assert!(scripts.contains(&scripts_path_2));
+    assert!(content_script_body_2.contains(&scripts_body_2));
+  }
+
+  #[test]
+  fn setting_only_incl_dest() {
+
+    let [
+      _, path_dir_scripts, path_source, _, _, _,
+      content_script_output_filename_1, content_script_output_filename_2,
+      _, _, _, content_source_triple,
+      _, _, _, _, _,
+      content_script_body_1, content_script_body_2, _,
+      content_script_output_1, content_script_output_2, _
+    ] = test_values_end_to_end_get();
+
+    /* setup - one - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_triple, "test source"]
+    ]));
+
+    /* acquisitions - one */
+
+    let output_one_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-d", &path_dir_scripts, "-o", "1", &path_source]))
+      .output()
+      .unwrap();
+
+    let output_one = String::from_utf8_lossy(&output_one_raw.stdout);
+
+    let scripts_one = fs::read_dir(&path_dir_scripts).unwrap()
+      .map(|e| e.unwrap().path().display().to_string())
+      .collect::<Vec<_>>();
+    let scripts_one_path_1 = format!("{path_dir_scripts}/{content_script_output_filename_1}");
+    let scripts_one_body_1 = fs::read_to_string(&scripts_one_path_1).unwrap();
+
+    test_tree_remove();
+
+    /* setup - two - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_triple, "test source"]
+    ]));
+
+    /* acquisitions - two */
+
+    let output_two_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-d", &path_dir_scripts, "-o", "2-3", &path_source]))
+      .output()
+      .unwrap();
+
+    let output_two = String::from_utf8_lossy(&output_two_raw.stdout);
+
+    let scripts_two = fs::read_dir(&path_dir_scripts).unwrap()
+      .map(|e| e.unwrap().path().display().to_string())
+      .collect::<Vec<_>>();
+    let scripts_two_path_2 = format!("{path_dir_scripts}/{content_script_output_filename_2}");
+    let scripts_two_body_2 = fs::read_to_string(&scripts_two_path_2).unwrap();
+
+    test_tree_remove();
+
+    /* setup - two - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_triple, "test source"]
+    ]));
+
+    /* acquisitions - all */
+
+    let output_all_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-d", &path_dir_scripts, "-o", "1,2-3", &path_source]))
+      .output()
+      .unwrap();
+
+    let output_all = String::from_utf8_lossy(&output_all_raw.stdout);
+
+    let scripts_all = fs::read_dir(&path_dir_scripts).unwrap()
+      .map(|e| e.unwrap().path().display().to_string())
+      .collect::<Vec<_>>();
+    let scripts_all_path_1 = format!("{path_dir_scripts}/{content_script_output_filename_1}");
+    let scripts_all_path_2 = format!("{path_dir_scripts}/{content_script_output_filename_2}");
+    let scripts_all_body_1 = fs::read_to_string(&scripts_all_path_1).unwrap();
+    let scripts_all_body_2 = fs::read_to_string(&scripts_all_path_2).unwrap();
+
+    test_tree_remove();
+
+    /* assertions - one */
+
+    assert_eq!(output_one.to_string(), format!("{content_script_output_1}"));
+
+    assert_eq!(scripts_one.len(), 1);
+    assert!(scripts_one.contains(&scripts_one_path_1));
+    assert!(content_script_body_1.contains(&scripts_one_body_1));
+
+    /* assertions - two */
+
+    assert_eq!(output_two.to_string(), format!("{content_script_output_2}"));
+
+    assert_eq!(scripts_two.len(), 1);
+    assert!(scripts_two.contains(&scripts_two_path_2));
+    assert!(content_script_body_2.contains(&scripts_two_body_2));
+
+    /* assertions - all */
+
+    assert_eq!(output_all.to_string(), format!("{content_script_output_1}{content_script_output_2}"));
+
+    assert_eq!(scripts_all.len(), 2);
+    assert!(scripts_all.contains(&scripts_all_path_1));
+    assert!(content_script_body_1.contains(&scripts_all_body_1));
+    assert!(scripts_all.contains(&scripts_all_path_2));
+    assert!(content_script_body_2.contains(&scripts_all_body_2));
+  }
+
+  #[test]
+  fn setting_list() {
+
+    let [
+      _, _, path_source, _, _, _,
+      _, _,
+      _, _, _, content_source_triple,
+      content_script_line_base_1, content_script_line_base_2, _, _, content_script_line_label,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_triple, "test source"]
+    ]));
+
+    /* acquisitions */
+
+    let output_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-l", &path_source]))
+      .output()
+      .unwrap();
+
+    let output = String::from_utf8_lossy(&output_raw.stdout);
+    let output_lines = output
+      .lines()
+      .collect::<Vec<_>>();
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert!(output_lines[0].contains("1"));
+    assert!(output_lines[0].contains(&content_script_line_base_1));
+
+    assert!(output_lines[1].contains("2"));
+    assert!(output_lines[1].contains(&content_script_line_label));
+    assert!(output_lines[1].contains(&content_script_line_base_2));
+
+    assert!(output_lines[2].contains("3"));
+    assert!(output_lines[2].contains(DEFAULTS[5].1));
+  }
+
+  #[test]
+  fn setting_dry_run_incl_shell() {
+
+    let [
+      _, path_dir_scripts, path_source, _, _, _,
+      _, _,
+      content_source_preface, _, _, _,
+      _, _, _, _, _,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    let content_source = format!("{content_source_preface}{} {}sh,-ec sh cat {} | wc -l\necho\n", DEFAULTS[3].1, DEFAULTS[11].1, DEFAULTS[7].1.replace("{}", ""));
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source, "test source"]
+    ]));
+
+    /* acquisitions */
+
+    let output_raw_prefix = test_command_get()
+      .args(Vec::from(["run", "--", "-n", &path_source]))
+      .output()
+      .unwrap();
+    fs::write(&path_source, content_source.replace(&format!("{}sh,-ec ", DEFAULTS[11].1), "")).unwrap();
+    let output_raw_option = test_command_get()
+      .args(Vec::from(["run", "--", "-n", "-s", "zsh", &path_source]))
+      .output()
+      .unwrap();
+
+    let output_prefix = String::from_utf8_lossy(&output_raw_prefix.stdout).to_string();
+    let output_option = String::from_utf8_lossy(&output_raw_option.stdout).to_string();
+    let is_saved = Path::new(&path_dir_scripts).exists();
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert!(output_prefix.contains("1: save to"));
+    assert!(output_prefix.contains("1: run with shell 'sh -ec': cat"));
+    assert!(output_option.contains("1: run with shell 'zsh -c': cat"));
+    assert!(!is_saved);
+  }
+
+  #[test]
+  fn setting_init() {
+
+    let [
+      _, _, path_source, _, _, _,
+      _, _,
+      _, _, _, _,
+      _, _, _, _, _,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::new());
+
+    /* acquisitions */
+
+    let output_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-i", &path_source]))
+      .output()
+      .unwrap();
+
+    let output = String::from_utf8_lossy(&output_raw.stdout);
+    let source = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+
+    let output_raw_run = test_command_get()
+      .args(Vec::from(["run", "--", &path_source]))
+      .output()
+      .unwrap();
+
+    let defaults = ConfigDefaults::from(DEFAULTS, COMMANDS);
+    let settings = settings_new(&defaults);
+    let messages = messages_new(&defaults);
+
+    let config_init = Config {
+      defaults,
+      settings,
+      messages,
+      receipts: HashMap::new(),
+      text_src: None,
+      dir_base: None
+    };
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert!(output.contains(&path_source));
+    assert!(output_raw_run.status.success());
+    assert!(source.contains(config_init.messages.expect("file")));
+    assert!(source.contains(config_init.messages.expect("line")));
+    assert!(source.contains(config_init.messages.expect("main")));
+    assert!(source.contains(config_init.messages.expect("plus")));
+    assert!(source.contains(config_init.messages.expect("pipe")));
+  }
+
+  #[test]
+  fn setting_push() {
+
+    let [
+      _, _, path_source, path_script, _, _,
+      _, _,
+      _, _, content_source_single, _,
+      content_script_line_base_1, _, content_script_line_tagged, _, _,
+      content_script_body, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_single, "test source"     ],
+      [&path_script, &content_script_body,   "test script body"]
+    ]));
+
+    /* acquisitions */
+
+    let output_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-p", &content_script_line_base_1, &path_script, &path_source]))
+      .output()
+      .unwrap();
+    let output = String::from_utf8_lossy(&output_raw.stdout);
+    let source = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+    let source_line = source.lines().nth(4).unwrap();
+
+    let output_tagged_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-p", &content_script_line_tagged, &path_script, &path_source]))
+      .output()
+      .unwrap();
+    let output_tagged = String::from_utf8_lossy(&output_tagged_raw.stdout);
+    let source_tagged = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+    let source_tagged_line = source_tagged.lines().nth(4).unwrap();
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert!(output.contains(&content_script_line_tagged));
+    assert!(output.contains(&path_script));
+    assert!(source.contains(&content_source_single));
+    assert!(source.contains(&content_script_body));
+    assert_eq!(content_script_line_tagged, source_line);
+
+    assert!(output_tagged.contains(&content_script_line_tagged));
+    assert!(output_tagged.contains(&path_script));
+    assert!(source_tagged.contains(&content_source_single));
+    assert!(source_tagged.contains(&content_script_body));
+    assert_eq!(content_script_line_tagged, source_tagged_line);
+  }
+
+  #[test]
+  fn setting_push_stdin() {
+
+    let [
+      _, _, path_source, _, _, _,
+      _, _,
+      _, _, content_source_single, _,
+      content_script_line_base_1, _, content_script_line_tagged, _, _,
+      content_script_body, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_single, "test source"]
+    ]));
+
+    /* acquisitions */
+
+    let mut proc = test_command_get()
+      .args(Vec::from(["run", "--", "--push-stdin", &content_script_line_base_1, &path_source]))
+      .stdin(process::Stdio::piped())
+      .stdout(process::Stdio::piped())
+      .stderr(process::Stdio::piped())
+      .spawn()
+      .unwrap();
+    proc.stdin
+      .take()
+      .unwrap()
+      .write_all(content_script_body.as_bytes())
+      .unwrap();
+    let output_raw = proc
+      .wait_with_output()
+      .unwrap();
+    let output = String::from_utf8_lossy(&output_raw.stdout);
+    let source = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+    let source_line = source.lines().nth(4).unwrap();
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert!(output.contains(&content_script_line_tagged));
+    assert!(output.contains("content on stdin"));
+    assert!(source.contains(&content_source_single));
+    assert!(source.ends_with(&content_script_body));
+    assert_eq!(content_script_line_tagged, source_line);
+  }
+
+  #[test]
+  fn source_stdin() {
+
+    let [
+      path_dir, _, path_source, _, _, _,
+      _, _,
+      _, content_source_script_body, content_source_single, _,
+      _, _, _, _, _,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_single, "test source"]
+    ]));
+
+    let run_piped = |args: Vec<&str>| {
+      let mut proc = test_command_get()
+        .args([Vec::from(["run", "--"]), args].concat())
+        .stdin(process::Stdio::piped())
+        .stdout(process::Stdio::piped())
+        .stderr(process::Stdio::piped())
+        .spawn()
+        .unwrap();
+      proc.stdin
+        .take()
+        .unwrap()
+        .write_all(content_source_single.as_bytes())
+        .unwrap();
+      proc
+        .wait_with_output()
+        .unwrap()
+    };
+
+    /* acquisitions */
+
+    let output_default = run_piped(Vec::from(["-d", &path_dir, "-"]));
+    let exists_manifest = fs::metadata(format!("{path_dir}/{}/manifest", DEFAULTS[2].1)).is_ok();
+    let script_default = fs::read_to_string(format!("{path_dir}/{}.sh", DEFAULTS[0].1.split('.').next().unwrap()));
+    let output_stem = run_piped(Vec::from(["-d", &path_dir, "--stem", "test", "-"]));
+    let script_stem = fs::read_to_string(format!("{path_dir}/test.sh"));
+    let output_edit = run_piped(Vec::from(["-e", "1", "sh", "-"]));
+    let output_stem_bad = run_piped(Vec::from(["--stem", "a/b", "-"]));
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert!(output_default.status.success());
+    assert!(String::from_utf8_lossy(&output_default.stdout).contains("Running initial"));
+    assert_eq!(content_source_script_body.trim_end(), script_default.unwrap());
+    assert!(exists_manifest);
+    assert!(output_stem.status.success());
+    assert_eq!(content_source_script_body.trim_end(), script_stem.unwrap());
+    assert_eq!(Some(2), output_edit.status.code());
+    assert!(String::from_utf8_lossy(&output_edit.stderr).contains("source on stdin"));
+    assert_eq!(Some(2), output_stem_bad.status.code());
+  }
+
+  #[test]
+  fn source_non_ascii() {
+
+    let [
+      path_dir, _, path_source, _, _, _,
+      _, _,
+      _, _, _, _,
+      _, _, _, _, _,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    let content_source = "# Préface\n§ ☃ → >/ü/scrïpt.sh sh\necho \"Rünning ☃\"\n";
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, content_source, "test source"]
+    ]));
+
+    let args_tag = ["-H", "§", "-T", "→"];
+    let label = "Étiquette ラベル";
+
+    /* acquisitions */
+
+    let output_list_raw = test_command_get()
+      .args([&["run", "--"], &args_tag[..], &["-l", &path_source]].concat())
+      .output()
+      .unwrap();
+    let output_list = String::from_utf8_lossy(&output_list_raw.stdout);
+
+    let output_label_raw = test_command_get()
+      .args([&["run", "--"], &args_tag[..], &["-b", "1", label, &path_source]].concat())
+      .output()
+      .unwrap();
+
+    let output_run_raw = test_command_get()
+      .args([&["run", "--"], &args_tag[..], &["-d", &path_dir, &path_source]].concat())
+      .output()
+      .unwrap();
+    let output_run = String::from_utf8_lossy(&output_run_raw.stdout);
+
+    let source = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+    let script = fs::read_to_string(format!("{path_dir}/ü/scrïpt.sh"));
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert!(output_list.contains("1: ☃ : >/ü/scrïpt.sh sh"));
+    assert!(output_label_raw.status.success());
+    assert_eq!(Some(format!("§ {label} → >/ü/scrïpt.sh sh")), source.lines().nth(1).map(String::from));
+    assert!(output_run.contains("Rünning ☃"));
+    assert_eq!("echo \"Rünning ☃\"", script.unwrap());
+  }
+
+  #[test]
+  fn setting_base() {
+
+    let [
+      path_dir, _, path_source, _, _, _,
+      _, _,
+      _, _, content_source_single, _,
+      _, _, _, _, _,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    let content_source_hashbang = format!("#!/usr/bin/env aliesce\n{content_source_single}");
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_hashbang, "test source"]
+    ]));
+
+    let run_dry = |args: &[&str]| {
+      let output_raw = test_command_get()
+        .args([&["run", "--", "-n"], args, &[&path_source]].concat())
+        .output()
+        .unwrap();
+      (output_raw.status.code(), String::from_utf8_lossy(&output_raw.stdout).to_string())
+    };
+
+    /* acquisitions */
+
+    let output_auto   = run_dry(&[]);
+    let output_source = run_dry(&["-B", "source", "-d", "out"]);
+    let output_cwd    = run_dry(&["-B", "cwd"]);
+    let output_bad    = run_dry(&["-B", "bad"]);
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert!(output_auto.1.contains(&format!("save to '{path_dir}/{}/source.sh'", DEFAULTS[1].1)));
+    assert!(output_source.1.contains(&format!("save to '{path_dir}/out/source.sh'")));
+    assert!(output_cwd.1.contains(&format!("save to '{}/source.sh'", DEFAULTS[1].1)));
+    assert_eq!(Some(2), output_bad.0);
+  }
+
+  #[test]
+  fn setting_force() {
+
+    let [
+      path_dir, _, path_source, _, _, _,
+      _, _,
+      _, _, content_source_single, _,
+      _, _, _, _, _,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_single, "test source"]
+    ]));
+
+    let path_script = format!("{path_dir}/source.sh");
+    let path_manifest = format!("{path_dir}/{}/manifest", DEFAULTS[2].1);
+    let content_edited = "echo \"Edited\"";
+
+    let path_source_pair = format!("{path_dir}/source_pair.txt");
+    let path_script_first = format!("{path_dir}/first.sh");
+    let path_script_second = format!("{path_dir}/second.sh");
+    let content_source_pair = format!("{tag_head} first.sh sh\necho \"Ran first\"\n{tag_head} second.sh sh\necho \"Ran second\"\n", tag_head = DEFAULTS[3].1);
+    fs::write(&path_source_pair, content_source_pair).unwrap();
+    fs::write(&path_script_second, content_edited).unwrap();
+
+    let run_for = |path_source: &str, args: &[&str]| {
+      test_command_get()
+        .args([&["run", "--", "-d", &path_dir], args, &[path_source]].concat())
+        .output()
+        .unwrap()
+    };
+    let run = |args: &[&str]| run_for(&path_source, args);
+
+    /* acquisitions */
+
+    let output_first = run(&[]);
+    let manifest = fs::read_to_string(&path_manifest);
+    let modified_first = fs::metadata(&path_script).unwrap().modified().unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    let output_unchanged = run(&[]);
+    let modified_unchanged = fs::metadata(&path_script).unwrap().modified().unwrap();
+
+    fs::write(&path_script, content_edited).unwrap();
+    let output_refused = run(&[]);
+    let script_refused = fs::read_to_string(&path_script).unwrap();
+    let output_preview = run(&["-n"]);
+    let output_forced = run(&["--force"]);
+    let script_forced = fs::read_to_string(&path_script).unwrap();
+
+    let output_untracked = run_for(&path_source_pair, &[]);
+    let script_untracked = fs::read_to_string(&path_script_second).unwrap();
+    let manifest_untracked = fs::read_to_string(&path_manifest);
+
+    fs::remove_file(&path_script_first).unwrap();
+    fs::write(&path_script_second, content_edited).unwrap();
+    let output_pair_refused = run_for(&path_source_pair, &[]);
+    let exists_pair_first = fs::metadata(&path_script_first).is_ok();
+    let output_pair_preview = run_for(&path_source_pair, &["-n"]);
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert!(output_first.status.success());
+    assert!(manifest.unwrap().contains("source.sh"));
+    assert!(output_unchanged.status.success());
+    assert_eq!(modified_first, modified_unchanged);
+    assert_eq!(Some(6), output_refused.status.code());
+    assert!(String::from_utf8_lossy(&output_refused.stderr).contains("changed since last saved"));
+    assert_eq!(content_edited, script_refused);
+    assert!(output_preview.status.success());
+    assert!(String::from_utf8_lossy(&output_preview.stdout).contains("1: not saving, file changed since last saved"));
+    assert!(output_forced.status.success());
+    assert_ne!(content_edited, script_forced);
+
+    assert!(output_untracked.status.success());
+    assert!(String::from_utf8_lossy(&output_untracked.stdout).contains("Ran second"));
+    assert_ne!(content_edited, script_untracked);
+    assert!(manifest_untracked.unwrap().contains("second.sh"));
+
+    assert_eq!(Some(6), output_pair_refused.status.code());
+    assert!(!String::from_utf8_lossy(&output_pair_refused.stdout).contains("Ran first"));
+    assert!(!exists_pair_first);
+    assert!(String::from_utf8_lossy(&output_pair_preview.stdout).contains("2: not saving, file changed since last saved"));
+  }
+
+  #[test]
+  fn setting_edit() {
+
+    let [
+      _, _, path_source, _, _, _,
+      _, _,
+      content_source_preface, content_source_script_body, content_source_single, _,
+      content_script_line_base_1, _, content_script_line_tagged, _, _,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_single, "test source"]
+    ]));
+
+    let n_script = "1";
+
+    /* acquisitions */
+
+    let output_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-e", n_script, &content_script_line_base_1, &path_source]))
+      .output()
+      .unwrap();
+    let output = String::from_utf8_lossy(&output_raw.stdout);
+    let source = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+    let source_line = source.lines().nth(1).unwrap();
+
+    let output_tagged_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-e", n_script, &content_script_line_tagged, &path_source]))
+      .output()
+      .unwrap();
+    let output_tagged = String::from_utf8_lossy(&output_tagged_raw.stdout);
+    let source_tagged = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+    let source_tagged_line = source_tagged.lines().nth(1).unwrap();
+
+    let output_absent_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-e", "99", &content_script_line_tagged, &path_source]))
+      .output()
+      .unwrap();
+    let source_absent = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert!(output.contains(n_script));
+    assert!(output.contains(&content_script_line_tagged));
+    assert!(source.contains(&content_source_preface));
+    assert!(source.contains(&content_source_script_body));
+    assert_eq!(content_script_line_tagged, source_line);
+
+    assert!(output_tagged.contains(n_script));
+    assert!(output_tagged.contains(&content_script_line_tagged));
+    assert!(source_tagged.contains(&content_source_preface));
+    assert!(source_tagged.contains(&content_source_script_body));
+    assert_eq!(content_script_line_tagged, source_tagged_line);
+
+    assert_eq!(Some(2), output_absent_raw.status.code());
+    assert!(String::from_utf8_lossy(&output_absent_raw.stderr).contains("no such script"));
+    assert_eq!(source_tagged, source_absent);
+  }
+
+  #[test]
+  fn setting_label() {
+
+    let [
+      _, _, path_source, _, _, _,
+      _, _,
+      _, _, _, content_source_triple,
+      content_script_line_base_1, content_script_line_base_2, _, _, _,
+      content_script_body_1, content_script_body_2, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_triple, "test source"]
+    ]));
+
+    let label = "Label new";
+    let content_script_line_labelled_1 = format!("{} {label} {} {content_script_line_base_1}", DEFAULTS[3].1, DEFAULTS[4].1);
+    let content_script_line_unlabelled_2 = format!("{} {content_script_line_base_2}", DEFAULTS[3].1);
+
+    /* acquisitions */
+
+    let output_added_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-b", "1", label, &path_source]))
+      .output()
+      .unwrap();
+    let output_added = String::from_utf8_lossy(&output_added_raw.stdout);
+
+    let output_removed_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-b", "2", "", &path_source]))
+      .output()
+      .unwrap();
+    let output_removed = String::from_utf8_lossy(&output_removed_raw.stdout);
+
+    let outputs_invalid_raw = [format!("a{}b", DEFAULTS[4].1), format!("a{}b", DEFAULTS[3].1), String::from("a\nb")]
+      .iter()
+      .map(|label_invalid| test_command_get()
+        .args(Vec::from(["run", "--", "-b", "1", label_invalid, &path_source]))
+        .output()
+        .unwrap())
+      .collect::<Vec<_>>();
+
+    let source = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+    let source_line_1 = source.lines().nth(1).unwrap();
+    let source_line_2 = source.lines().nth(3).unwrap();
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert!(output_added.contains(label));
+    assert!(output_removed.contains("Removed label"));
+    assert!(outputs_invalid_raw.iter().all(|output_raw| Some(2) == output_raw.status.code()));
+
+    assert_eq!(content_script_line_labelled_1, source_line_1);
+    assert_eq!(content_script_line_unlabelled_2, source_line_2);
+    assert!(source.contains(&content_script_body_1));
+    assert!(source.contains(&content_script_body_2));
+  }
+
+  #[test]
+  fn setting_retag() {
+
+    let [
+      _, _, path_source, _, _, _,
+      _, _,
+      _, _, _, content_source_triple,
+      _, content_script_line_base_2, content_script_line_tagged, _, content_script_line_label,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_triple, "test source"]
+    ]));
+
+    let content_script_line_retagged_2 = format!(
+      "{} {content_script_line_label} {} {}",
+      DEFAULTS[3].1,
+      DEFAULTS[4].1,
+      content_script_line_base_2.replace(" sh", " bash")
+    );
+
+    /* acquisitions */
+
+    let output_listed_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-r", " (sh)$", " ba$1", "-o", "2", "-l", &path_source]))
+      .output()
+      .unwrap();
+    let output_listed = String::from_utf8_lossy(&output_listed_raw.stdout);
+    let source_listed = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+
+    let replacement_break = format!(" sh\n{} py", DEFAULTS[3].1);
+    let replacement_tail = format!(" {} sh", DEFAULTS[4].1);
+    let outputs_invalid_raw = [replacement_break, replacement_tail]
+      .map(|replacement| {
+        test_command_get()
+          .args(Vec::from(["run", "--", "-r", " sh$", &replacement, "-o", "1", &path_source]))
+          .output()
+          .unwrap()
+      });
+    let source_invalid = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+
+    let output_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-r", " (sh)$", " ba$1", "-o", "2", &path_source]))
+      .output()
+      .unwrap();
+    let output = String::from_utf8_lossy(&output_raw.stdout);
+    let source = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+    let source_line_1 = source.lines().nth(1).unwrap();
+    let source_line_2 = source.lines().nth(3).unwrap();
+
+    test_tree_remove();
+
+    /* assertions */
+
+    assert!(output_listed.contains(&format!("+ {content_script_line_retagged_2}")));
+    assert_eq!(content_source_triple, source_listed);
+
+    assert!(outputs_invalid_raw.iter().all(|output_raw| Some(2) == output_raw.status.code()));
+    assert_eq!(content_source_triple, source_invalid);
+
+    assert!(output.contains(&format!("+ {content_script_line_retagged_2}")));
+    assert_eq!(content_script_line_tagged, source_line_1);
+    assert_eq!(content_script_line_retagged_2, source_line_2);
+  }
+
+  #[test]
+  fn setting_extract_incl_copy() {
+
+    let [
+      path_dir, _, path_source, _, _, _,
+      _, _,
+      content_source_preface, _, _, content_source_triple,
+      _, content_script_line_base_2, content_script_line_tagged, content_script_line_tagged_bypass, content_script_line_label,
+      content_script_body_1, content_script_body_2, content_script_body_3,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    let path_dest = format!("{path_dir}/dest.txt");
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_triple, "test source"]
+    ]));
+
+    let content_script_line_tagged_labelled = format!("{} {content_script_line_label} {} {content_script_line_base_2}", DEFAULTS[3].1, DEFAULTS[4].1);
+    let content_script_1 = format!("{content_script_line_tagged}\n{content_script_body_1}");
+    let content_script_2 = format!("{content_script_line_tagged_labelled}\n{content_script_body_2}");
+    let content_script_3 = format!("{content_script_line_tagged_bypass}\n{content_script_body_3}");
+
+    let path_source_alias = format!("{path_dir}/../{path_dir}/source.txt");
+
+    /* acquisitions */
+
+    let output_same_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-x", "1", &path_source_alias, &path_source]))
+      .output()
+      .unwrap();
+    let source_same = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+
+    let output_moved_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-x", "2-3", &path_dest, &path_source]))
+      .output()
+      .unwrap();
+    let output_moved = String::from_utf8_lossy(&output_moved_raw.stdout);
+    let source_moved = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+    let dest_moved = fs::read_to_string(&path_dest)
+      .unwrap_or_else(|_| panic!("reading from test destination"));
 
-    println!("{title_line}\n\n{usage_text}\n{flags_text}\n\n{notes_text}");
-    process::exit(0);
-  }
+    let output_copied_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-c", "1", &path_dest, &path_source]))
+      .output()
+      .unwrap();
+    let output_copied = String::from_utf8_lossy(&output_copied_raw.stdout);
+    let source_copied = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+    let dest_copied = fs::read_to_string(&path_dest)
+      .unwrap_or_else(|_| panic!("reading from test destination"));
 
-  /* - utility functions */
+    test_tree_remove();
 
-  fn name_and_version_get() -> String {
-    format!("{} v{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
-  }
+    /* assertions - same as source */
 
-  fn line_center_with_fill(line: &str, length: usize, fill: &str) -> String {
-    let whitespace_half = String::from(fill).repeat((length - line.len() - 2) / 2);
-    let whitespace_last = if 0 == line.len() % 2 { "" } else { fill };
-    format!("{whitespace_half} {line} {whitespace_half}{whitespace_last}")
-  }
+    assert_eq!(Some(2), output_same_raw.status.code());
+    assert!(String::from_utf8_lossy(&output_same_raw.stderr).contains("same as source"));
+    assert_eq!(content_source_triple, source_same);
 
-  fn line_break_and_indent(line: &str, indent: usize, length: usize, indent_first: bool ) -> String {
+    /* assertions - moved */
 
-    let whitespace_part = String::from(" ").repeat(indent);
-    let whitespace_full = format!("\n{whitespace_part}");
-    let text_width = length - indent;
+    assert!(output_moved.contains("Moved"));
+    assert!(output_moved.contains(&path_dest));
+    assert_eq!(format!("{content_source_preface}{content_script_1}"), source_moved);
+    assert_eq!(format!("{content_source_preface}{content_script_2}{content_script_3}"), dest_moved);
 
-    let body = line
-      .split(' ')
-      .collect::<Vec<_>>()
-      .iter()
-      .fold(Vec::new(), |mut acc: Vec<String>, word| {
-        if acc.is_empty() { return Vec::from([String::from(*word)]) };
-        /* accrue text part of each line by word, not exceeding text width */
-        let index_last = acc.len() - 1;
-        match acc[index_last].chars().count() + word.chars().count() >= text_width {
-          /* begin new text part with word */
-          true => acc.push(String::from(*word)),
-          /* add word to current text part */
-          _    => acc[index_last].push_str(&format!(" {}", *word))
-        };
-        acc
-      })
-      .join(whitespace_full.as_str());
+    /* assertions - copied */
 
-    if indent_first { format!("{whitespace_part}{body}") } else { body }
+    assert!(output_copied.contains("Copied"));
+    assert_eq!(source_moved, source_copied);
+    assert_eq!(format!("{content_source_preface}{content_script_2}{content_script_3}{content_script_1}"), dest_copied);
   }
-}
 
-/* TEST */
+  #[test]
+  fn setting_merge() {
 
-#[cfg(test)]
-mod test {
+    let [
+      path_dir, _, path_source, _, _, _,
+      _, _,
+      _, _, content_source_single, content_source_triple,
+      _, _, _, _, _,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
 
-  /* - imports */
+    let path_other = format!("{path_dir}/other.txt");
 
-  use::std::io::Write;
-  use::std::fs;
-  use::std::process;
-  use::std::collections::HashMap;
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_triple, "test source"      ],
+      [&path_other,  &content_source_single, "test other source"]
+    ]));
 
-  use super::{
-    DEFAULTS,
-    Script,
-    settings_new,
-    messages_new,
-    inputs_parse
-  };
-  use crate::output::{
-    Output,
-    OutputText,
-    OutputFile,
-    OutputFilePath,
-    OutputFileInit,
-    OutputFileInitCode
-  };
-  use crate::config::{
-    Config,
-    ConfigDefaults,
-    ConfigReceiptVal
-  };
+    let path_source_alias = format!("{path_dir}/../{path_dir}/source.txt");
 
-  /* - test cases */
+    /* acquisitions */
 
-  /*   - end-to-end */
+    let output_same_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-m", &path_source_alias, &path_source]))
+      .output()
+      .unwrap();
+    let source_same = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
 
-  /*     - stdin read, settings */
+    let output_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-m", &path_other, &path_source]))
+      .output()
+      .unwrap();
+    let output = String::from_utf8_lossy(&output_raw.stdout);
+    let source = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
+    let other = fs::read_to_string(&path_other)
+      .unwrap_or_else(|_| panic!("reading from test other source"));
 
-  const PATH_TMP_DIR_TEST: &str = "./.test_temp";
+    test_tree_remove();
 
-  fn test_values_script_get(path_dir: &String, n: u8) -> (String, String, String, String, String) {
-    let output_filename = format!("test_{n}.sh");
-    let string = format!("Running {n}");
-    let output = format!("{string}\n");
-    (
-      format!("{path_dir}/script_{n}.txt"),
-      format!(">/{output_filename} sh"),
-      format!("echo \"{string}\"\n"),
-      output_filename,
-      output
-    )
+    /* assertions */
+
+    let content_other_scripts = content_source_single
+      .split_once('\n')
+      .unwrap()
+      .1;
+
+    assert_eq!(Some(2), output_same_raw.status.code());
+    assert_eq!(content_source_triple, source_same);
+
+    assert!(output.contains(&path_other));
+    assert_eq!(format!("{content_source_triple}{content_other_scripts}"), source);
+    assert_eq!(content_source_single, other);
   }
 
-  fn test_values_end_to_end_get() -> [String; 23] {
+  #[test]
+  fn setting_undo_incl_keep() {
 
-    let path_dir = String::from(PATH_TMP_DIR_TEST);
-    let path_dir_scripts = format!("{path_dir}/scripts");
-    let path_source      = format!("{path_dir}/source.txt");
+    let [
+      path_dir, _, path_source, _, _, _,
+      _, _,
+      _, _, content_source_single, _,
+      _, _, _, _, _,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
 
-    let (
-      path_script_1, content_script_line_base_1, content_script_body_1,
-      content_script_output_filename_1, content_script_output_1
-    ) = test_values_script_get(&path_dir, 1);
-    let (
-      path_script_2, content_script_line_base_2, content_script_body_2,
-      content_script_output_filename_2, content_script_output_2
-    ) = test_values_script_get(&path_dir, 2);
-    let (
-      path_script_3, _,                          content_script_body_3,
-       _,                                content_script_output_3
-    ) = test_values_script_get(&path_dir, 3);
+    let path_bak_dir = format!("{path_dir}/{}", DEFAULTS[2].1);
 
-    let content_source_preface = String::from("Test preface\n");
-    let content_source_script_line = format!("{} sh sh\n", DEFAULTS[3].1);
-    let content_source_script_body = format!("echo \"Running initial\"\n");
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::from([
+      [&path_source, &content_source_single, "test source"]
+    ]));
 
-    let content_source_single = format!("{content_source_preface}{content_source_script_line}{content_source_script_body}");
+    /* acquisitions */
 
-    let content_script_line_label = format!("Test label");
+    let mut sources_edited = Vec::new();
+    for line in ["sh bash", "sh dash", "sh zsh"] {
+      test_command_get()
+        .args(Vec::from(["run", "--", "-k", "2", "-e", "1", line, &path_source]))
+        .output()
+        .unwrap();
+      sources_edited.push(fs::read_to_string(&path_source).unwrap_or_else(|_| panic!("reading from test source")));
+    }
+    let backups_count = fs::read_dir(&path_bak_dir).unwrap()
+      .filter(|e| !e.as_ref().unwrap().path().display().to_string().ends_with(".lock"))
+      .count();
+
+    let mut outputs_undone = Vec::new();
+    let mut sources_undone = Vec::new();
+    for _ in 0..3 {
+      let output_raw = test_command_get()
+        .args(Vec::from(["run", "--", "-u", &path_source]))
+        .output()
+        .unwrap();
+      outputs_undone.push(output_raw);
+      sources_undone.push(fs::read_to_string(&path_source).unwrap_or_else(|_| panic!("reading from test source")));
+    }
 
-    let content_script_line_tagged          = format!("{} {content_script_line_base_1}", DEFAULTS[3].1);
-    let content_script_line_tagged_labelled = format!("{} {content_script_line_label} {} {content_script_line_base_2}", DEFAULTS[3].1, DEFAULTS[4].1);
-    let content_script_line_tagged_bypass   = format!("{} {}", DEFAULTS[3].1, DEFAULTS[5].1);
+    test_tree_remove();
 
-    let content_source_triple = format!("{content_source_preface}{content_script_line_tagged}\n{content_script_body_1}{content_script_line_tagged_labelled}\n{content_script_body_2}{content_script_line_tagged_bypass}\n{content_script_body_3}");
+    /* assertions */
 
-    [
-      path_dir, path_dir_scripts, path_source, path_script_1, path_script_2, path_script_3,
-      content_script_output_filename_1, content_script_output_filename_2,
-      content_source_preface, content_source_script_body, content_source_single, content_source_triple,
-      content_script_line_base_1, content_script_line_base_2, content_script_line_tagged, content_script_line_tagged_bypass, content_script_line_label,
-      content_script_body_1, content_script_body_2, content_script_body_3,
-      content_script_output_1, content_script_output_2, content_script_output_3
-    ]
-  }
+    assert_eq!(2, backups_count);
 
-  fn test_tree_create(files: Vec<[&str; 3]>) {
-    let path_dir = &test_values_end_to_end_get()[0];
-    fs::create_dir_all(&path_dir)
-      .unwrap_or_else(|_| panic!("create temporary test directory '{path_dir}'"));
-    for file in files {
-      let [path_file, content_file, description] = file;
-      fs::write(&path_file, &content_file).unwrap_or_else(|_| panic!("write {description} to '{path_file}'"));
-    }
-  }
+    assert!(String::from_utf8_lossy(&outputs_undone[0].stdout).contains("Restored"));
+    assert_eq!(sources_edited[1], sources_undone[0]);
+    assert_eq!(sources_edited[0], sources_undone[1]);
 
-  fn test_tree_remove() {
-    let path_dir = &test_values_end_to_end_get()[0];
-    fs::remove_dir_all(&path_dir)
-      .unwrap_or_else(|_| panic!("remove temporary test directory '{path_dir}'"));
+    assert_eq!(Some(4), outputs_undone[2].status.code());
+    assert!(String::from_utf8_lossy(&outputs_undone[2].stderr).contains("no backup found"));
+    assert_eq!(sources_edited[0], sources_undone[2]);
+    assert_ne!(content_source_single, sources_undone[2]);
   }
 
-  /*     - stdin read */
-
-  fn test_stdin_read_run(input_delimiter: &str) -> () {
+  #[test]
+  fn source_change_locked() {
 
     let [
-      _, _, path_source, path_script_1, path_script_2, path_script_3,
+      path_dir, _, path_source, path_script, _, _,
       _, _,
       _, _, content_source_single, _,
-      _, _, _, content_script_line_tagged_bypass, _,
-      content_script_body_1, content_script_body_2, content_script_body_3,
+      content_script_line_base_1, _, _, _, _,
+      content_script_body, _, _,
       _, _, _
     ] = test_values_end_to_end_get();
 
-    /* setup - add temporary test directory w/ content */
+    let path_bak_dir = format!("{path_dir}/{}", DEFAULTS[2].1);
+    let path_lock = format!("{path_bak_dir}/source.txt.lock");
+
+    /* setup - add temporary test directory w/ content and hold lock */
     test_tree_create(Vec::from([
-      [&path_source,   &content_source_single, "test source"       ],
-      [&path_script_1, &content_script_body_1, "test script 1 body"],
-      [&path_script_2, &content_script_body_2, "test script 2 body"],
-      [&path_script_3, &content_script_body_3, "test script 3 body"]
+      [&path_source, &content_source_single, "test source"     ],
+      [&path_script, &content_script_body,   "test script body"]
     ]));
+    fs::create_dir_all(&path_bak_dir).unwrap();
+    let lock = fs::File::create(&path_lock).unwrap();
+    lock.try_lock().unwrap();
 
     /* acquisitions */
 
-    let mut proc = process::Command::new("cargo")
-      .args(Vec::from(["run", "--", &path_source]))
-      .stdin(process::Stdio::piped())
-      .stdout(process::Stdio::piped())
-      .stderr(process::Stdio::piped())
-      .spawn()
-      .unwrap();
-
-    let input = format!("{path_script_1}{d}{path_script_2}{d}{path_script_3}", d = input_delimiter);
-
-    proc.stdin
-      .take()
-      .unwrap()
-      .write_all(input.as_bytes())
+    let output_edit_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-e", "1", &content_script_line_base_1, &path_source]))
+      .output()
       .unwrap();
-    let output_raw = proc
-      .wait_with_output()
+    let output_push_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-p", &content_script_line_base_1, &path_script, &path_source]))
+      .output()
       .unwrap();
-
-    let output = String::from_utf8_lossy(&output_raw.stdout);
     let source = fs::read_to_string(&path_source)
       .unwrap_or_else(|_| panic!("reading from test source"));
-    let source_line_1 = source.lines().nth( 4).unwrap();
-    let source_line_2 = source.lines().nth( 8).unwrap();
-    let source_line_3 = source.lines().nth(12).unwrap();
 
+    lock.unlock().unwrap();
     test_tree_remove();
 
     /* assertions */
 
-    assert!(output.contains(&content_script_line_tagged_bypass));
-    assert!(output.contains(&path_script_1));
-    assert!(output.contains(&path_script_2));
-    assert!(output.contains(&path_script_3));
-
-    assert!(source.contains(&content_source_single));
-    assert_eq!(content_script_line_tagged_bypass, source_line_1);
-    assert_eq!(content_script_line_tagged_bypass, source_line_2);
-    assert_eq!(content_script_line_tagged_bypass, source_line_3);
-    assert!(source.contains(&content_script_body_1));
-    assert!(source.contains(&content_script_body_2));
-    assert!(source.contains(&content_script_body_3));
+    for output_raw in [output_edit_raw, output_push_raw] {
+      assert_eq!(Some(5), output_raw.status.code());
+      assert!(String::from_utf8_lossy(&output_raw.stderr).contains("locked by another process"));
+    }
+    assert_eq!(content_source_single, source);
   }
 
   #[test]
-  fn stdin_read() {
+  fn source_diagnostics() {
 
-    let input_delimiter_1 = " ";
-    let input_delimiter_2 = "\n";
+    let [
+      _, _, path_source, _, _, _,
+      _, _,
+      content_source_preface, _, _, _,
+      _, _, _, _, _,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    let tag_head = DEFAULTS[3].1;
+    let tag_tail = DEFAULTS[4].1;
+    let contents_source = [3, 1, 2].map(|n| {
+      let plc = DEFAULTS[7].1.replace("{}", &n.to_string());
+      format!("{content_source_preface}{tag_head} sh echo {plc}\necho\n{tag_head} label {tag_tail}\necho\n")
+    });
+
+    /* setup - add temporary test directory */
+    test_tree_create(Vec::new());
+
+    /* acquisitions */
 
-    test_stdin_read_run(input_delimiter_1);
-    test_stdin_read_run(input_delimiter_2);
+    let outputs = contents_source
+      .iter()
+      .map(|content_source| {
+        fs::write(&path_source, content_source).unwrap();
+        let output_raw = test_command_get()
+          .args(Vec::from(["run", "--", "-n", &path_source]))
+          .output()
+          .unwrap();
+        (output_raw.status.code(), String::from_utf8_lossy(&output_raw.stderr).to_string())
+      })
+      .collect::<Vec<_>>();
+
+    test_tree_remove();
+
+    /* assertions */
+
+    let plc = DEFAULTS[7].1.replace("{}", "3");
+    let line_plc = format!("{tag_head} sh echo {plc}");
+    assert_eq!(Some(4), outputs[0].0);
+    assert!(outputs[0].1.contains(&format!(
+      "{path_source}:2:13: Not running file no. 1 (placeholder '{plc}' for script no. 3, not in source)\n  {line_plc}\n  {}^",
+      " ".repeat(12)
+    )));
+    assert_eq!(Some(4), outputs[2].0);
+    assert!(outputs[2].1.contains("for script no. 2, not saved in this run)"));
+
+    let line_label = format!("{tag_head} label {tag_tail}");
+    assert_eq!(Some(0), outputs[1].0);
+    assert!(outputs[1].1.contains(&format!(
+      "{path_source}:4:{}: No tag data found for script no. 2\n  {line_label}",
+      line_label.len() + 1
+    )));
   }
 
-  /*     - settings */
+  #[test]
+  fn error_exit_codes() {
+
+    let [
+      path_dir, _, path_source, _, _, _,
+      _, _,
+      _, _, _, _,
+      _, _, _, _, _,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    let tag_head = DEFAULTS[3].1;
+    let path_source_absent = format!("{path_dir}/absent.txt");
+    let path_file_as_dir = format!("{path_dir}/file");
+    let content_source_preface_bad = format!("not an option\n{tag_head} sh ! echo\necho\n");
+    let content_source_save_bad = format!("{tag_head} {path_file_as_dir}/test.sh ! echo\necho\n");
+    let content_source_exec_bad = format!("{tag_head} sh aliesce-test-absent-program\necho\n");
+
+    /* acquisitions */
+
+    let mut outputs = Vec::new();
+    for (content_source, args) in [
+      ("",                          Vec::from(["--unknown"])),
+      ("",                          Vec::from(["-o", "x", &path_source])),
+      (&content_source_preface_bad, Vec::from([path_source.as_str()])),
+      ("",                          Vec::from([path_source_absent.as_str()])),
+      (&content_source_save_bad,    Vec::from([path_source.as_str()])),
+      (&content_source_exec_bad,    Vec::from(["-d", &path_dir, &path_source]))
+    ] {
+      /* setup - add temporary test directory w/ content, incl. file in place of directory */
+      test_tree_create(Vec::from([
+        [&path_source,      content_source, "test source"],
+        [&path_file_as_dir, "",             "test file"  ]
+      ]));
+      outputs.push(test_command_get()
+        .args([Vec::from(["run", "--"]), args].concat())
+        .output()
+        .unwrap());
+      test_tree_remove();
+    }
+
+    /* assertions */
+
+    let codes = outputs
+      .iter()
+      .map(|output_raw| output_raw.status.code())
+      .collect::<Vec<_>>();
+    assert_eq!(Vec::from([Some(2), Some(2), Some(3), Some(4), Some(6), Some(7)]), codes);
+    assert!(String::from_utf8_lossy(&outputs[1].stderr).contains("Not parsing script no. 'x' for option 'only'"));
+    assert!(String::from_utf8_lossy(&outputs[5].stderr).contains("spawn error"));
+  }
 
   #[test]
-  fn setting_dest() {
+  fn config_files_incl_precedence() {
 
     let [
-      _, path_dir_scripts, path_source, _, _, _,
-      content_script_output_filename_1, content_script_output_filename_2,
-      _, _, _, content_source_triple,
+      path_dir, _, path_source, _, _, _,
+      _, _,
+      _, _, _, _,
       _, _, _, _, _,
       content_script_body_1, content_script_body_2, _,
       content_script_output_1, content_script_output_2, _
     ] = test_values_end_to_end_get();
 
+    let path_cfg_dir     = format!("{path_dir}/config");
+    let path_cfg_user    = format!("{path_cfg_dir}/{PATH_CFG_USER}");
+    let path_cfg_project = format!("{path_dir}/{PATH_CFG_PROJECT}");
+    let path_dir_user    = format!("{path_dir}/user");
+    let path_dir_project = format!("{path_dir}/project");
+    let path_dir_cli     = format!("{path_dir}/cli");
+
+    let content_cfg_user    = format!("# user\ntag_head = %%%\npath_dir = {path_dir_user}\n");
+    let content_cfg_project = format!("path_dir = {path_dir_project}\n");
+    let content_source      = format!("-o 1\n%%% test_1.sh sh\n{content_script_body_1}%%% test_2.sh sh\n{content_script_body_2}");
+
     /* setup - add temporary test directory w/ content */
     test_tree_create(Vec::from([
-      [&path_source, &content_source_triple, "test source"]
+      [&path_source,      &content_source,      "test source"                      ],
+      [&path_cfg_project, &content_cfg_project, "test project configuration file"]
     ]));
+    fs::create_dir_all(Path::new(&path_cfg_user).parent().unwrap()).unwrap();
+    fs::write(&path_cfg_user, &content_cfg_user).unwrap();
 
     /* acquisitions */
 
-    let output_raw = process::Command::new("cargo")
-      .args(Vec::from(["run", "--", "-d", &path_dir_scripts, &path_source]))
+    let output_files_raw = test_command_get()
+      .args(Vec::from(["run", "--", &path_source]))
+      .env("XDG_CONFIG_HOME", &path_cfg_dir)
       .output()
       .unwrap();
+    let output_files = String::from_utf8_lossy(&output_files_raw.stdout);
+    let exists_files_user    = fs::metadata(format!("{path_dir_user}/test_1.sh")).is_ok();
+    let exists_files_project = fs::metadata(format!("{path_dir_project}/test_1.sh")).is_ok();
 
-    let output = String::from_utf8_lossy(&output_raw.stdout);
-
-    let scripts = fs::read_dir(&path_dir_scripts).unwrap()
-      .map(|e| e.unwrap().path().display().to_string())
-      .collect::<Vec<_>>();
-    let scripts_path_1 = format!("{path_dir_scripts}/{content_script_output_filename_1}");
-    let scripts_path_2 = format!("{path_dir_scripts}/{content_script_output_filename_2}");
-    let scripts_body_1 = fs::read_to_string(&scripts_path_1).unwrap();
-    let scripts_body_2 = fs::read_to_string(&scripts_path_2).unwrap();
+    let output_cli_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-o", "2", "-d", &path_dir_cli, &path_source]))
+      .env("XDG_CONFIG_HOME", &path_cfg_dir)
+      .output()
+      .unwrap();
+    let output_cli = String::from_utf8_lossy(&output_cli_raw.stdout);
+    let exists_cli = fs::metadata(format!("{path_dir_cli}/test_2.sh")).is_ok();
 
     test_tree_remove();
 
     /* assertions */
 
-    assert_eq!(output.to_string(), format!("{content_script_output_1}{content_script_output_2}"));
+    assert_eq!(content_script_output_1, output_files);
+    assert!(!exists_files_user);
+    assert!(exists_files_project);
 
-    assert_eq!(scripts.len(), 2);
-    assert!(scripts.contains(&scripts_path_1));
-    assert!(content_script_body_1.contains(&scripts_body_1));
-    assert!(scripts.contains(&scripts_path_2));
-    assert!(content_script_body_2.contains(&scripts_body_2));
+    assert_eq!(content_script_output_2, output_cli);
+    assert!(exists_cli);
   }
 
   #[test]
-  fn setting_only_incl_dest() {
+  fn settings_syntax() {
 
     let [
-      _, path_dir_scripts, path_source, _, _, _,
-      content_script_output_filename_1, content_script_output_filename_2,
-      _, _, _, content_source_triple,
+      _, path_dir_scripts, path_source, path_script, _, _,
+      content_script_output_filename_1, _,
+      _, _, _, _,
       _, _, _, _, _,
-      content_script_body_1, content_script_body_2, _,
-      content_script_output_1, content_script_output_2, _
+      content_script_body_1, _, content_script_body_3,
+      content_script_output_1, _, _
     ] = test_values_end_to_end_get();
 
-    /* setup - one - add temporary test directory w/ content */
+    let content_source_preface = "--head %%\n--tail ::\n# Test comment\n\n--stop '~'\n--plc-dir=+\n";
+    let content_source = format!("{content_source_preface}%% Label :: +/{content_script_output_filename_1} sh\n{content_script_body_1}%% ~ ext\n{content_script_body_3}");
+
+    /* setup - add temporary test directory w/ content */
     test_tree_create(Vec::from([
-      [&path_source, &content_source_triple, "test source"]
+      [&path_source, &content_source,        "test source"     ],
+      [&path_script, &content_script_body_1, "test script body"]
     ]));
 
-    /* acquisitions - one */
+    /* acquisitions */
 
-    let output_one_raw = process::Command::new("cargo")
-      .args(Vec::from(["run", "--", "-d", &path_dir_scripts, "-o", "1", &path_source]))
+    let output_raw = test_command_get()
+      .args(Vec::from(["run", "--", "-d", &path_dir_scripts, &path_source]))
       .output()
       .unwrap();
+    let output = String::from_utf8_lossy(&output_raw.stdout);
+    let output_err = String::from_utf8_lossy(&output_raw.stderr);
+    let exists_script = fs::metadata(format!("{path_dir_scripts}/{content_script_output_filename_1}")).is_ok();
 
-    let output_one = String::from_utf8_lossy(&output_one_raw.stdout);
+    test_command_get()
+      .args(Vec::from(["run", "--", "-b", "1", "Label new", &path_source]))
+      .output()
+      .unwrap();
+    let source_labelled = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
 
-    let scripts_one = fs::read_dir(&path_dir_scripts).unwrap()
-      .map(|e| e.unwrap().path().display().to_string())
-      .collect::<Vec<_>>();
-    let scripts_one_path_1 = format!("{path_dir_scripts}/{content_script_output_filename_1}");
-    let scripts_one_body_1 = fs::read_to_string(&scripts_one_path_1).unwrap();
+    test_command_get()
+      .args(Vec::from(["run", "--", "-H", "§§", "-p", "sh sh", &path_script, &path_source]))
+      .output()
+      .unwrap();
+    let source_pushed = fs::read_to_string(&path_source)
+      .unwrap_or_else(|_| panic!("reading from test source"));
 
     test_tree_remove();
 
-    /* setup - two - add temporary test directory w/ content */
+    /* assertions */
+
+    assert_eq!(content_script_output_1, output);
+    assert!(output_err.contains("~ applied"));
+    assert!(exists_script);
+
+    assert_eq!(format!("%% Label new :: +/{content_script_output_filename_1} sh"), source_labelled.lines().nth(6).unwrap());
+    assert!(source_pushed.contains("\n§§ sh sh\n"));
+  }
+
+  #[test]
+  fn settings_parsing() {
+
+    let [
+      _, _, path_source, _, _, _,
+      _, _,
+      _, _, _, content_source_triple,
+      content_script_line_base_1, content_script_line_base_2, _, _, _,
+      _, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    /* setup - add temporary test directory w/ content */
     test_tree_create(Vec::from([
       [&path_source, &content_source_triple, "test source"]
     ]));
 
-    /* acquisitions - two */
-
-    let output_two_raw = process::Command::new("cargo")
-      .args(Vec::from(["run", "--", "-d", &path_dir_scripts, "-o", "2-3", &path_source]))
-      .output()
-      .unwrap();
-
-    let output_two = String::from_utf8_lossy(&output_two_raw.stdout);
+    /* acquisitions */
 
-    let scripts_two = fs::read_dir(&path_dir_scripts).unwrap()
-      .map(|e| e.unwrap().path().display().to_string())
+    let outputs = [
+      Vec::from(["--only=2", "-l", &path_source]),
+      Vec::from(["-lo2", "--", &path_source]),
+      Vec::from(["-lo", "1", "-o", "2", &path_source]),
+      Vec::from(["-l", &path_source, "-o"]),
+      Vec::from(["-l", "--lost", &path_source]),
+      Vec::from(["-l", &path_source, &path_source]),
+      Vec::from(["-l", "-d", "a", "--dest", "b", &path_source]),
+      Vec::from(["-lo", "3-1", &path_source])
+    ]
+      .into_iter()
+      .map(|args| {
+        let output_raw = test_command_get()
+          .args([Vec::from(["run", "--"]), args].concat())
+          .output()
+          .unwrap();
+        (
+          output_raw.status.success(),
+          String::from_utf8_lossy(&output_raw.stdout).to_string(),
+          String::from_utf8_lossy(&output_raw.stderr).to_string()
+        )
+      })
       .collect::<Vec<_>>();
-    let scripts_two_path_2 = format!("{path_dir_scripts}/{content_script_output_filename_2}");
-    let scripts_two_body_2 = fs::read_to_string(&scripts_two_path_2).unwrap();
 
     test_tree_remove();
 
-    /* setup - two - add temporary test directory w/ content */
-    test_tree_create(Vec::from([
-      [&path_source, &content_source_triple, "test source"]
-    ]));
+    /* assertions */
 
-    /* acquisitions - all */
+    assert!(outputs[0].0 && outputs[1].0 && outputs[2].0);
+    assert!(!outputs[0].1.contains(&content_script_line_base_1) && outputs[0].1.contains(&content_script_line_base_2));
+    assert_eq!(outputs[0].1, outputs[1].1);
+    assert!(outputs[2].1.contains(&content_script_line_base_1) && outputs[2].1.contains(&content_script_line_base_2));
 
-    let output_all_raw = process::Command::new("cargo")
-      .args(Vec::from(["run", "--", "-d", &path_dir_scripts, "-o", "1,2-3", &path_source]))
-      .output()
-      .unwrap();
+    assert!(!outputs[3].0 && outputs[3].2.contains("'-o' (missing SUBSET)"));
+    assert!(!outputs[4].0 && outputs[4].2.contains("'--lost' (unknown"));
+    assert!(!outputs[5].0 && outputs[5].2.contains("source path already given"));
+    assert!(!outputs[6].0 && outputs[6].2.contains("'--dest' (repeated, not repeatable)"));
+    assert!(!outputs[7].0 && outputs[7].2.contains("range '3-1'"));
+  }
 
-    let output_all = String::from_utf8_lossy(&output_all_raw.stdout);
+  #[test]
+  fn source_preface() {
 
-    let scripts_all = fs::read_dir(&path_dir_scripts).unwrap()
-      .map(|e| e.unwrap().path().display().to_string())
+    let [
+      _, _, path_source, _, _, _,
+      _, _,
+      _, _, _, _,
+      _, _, content_script_line_tagged, _, _,
+      content_script_body_1, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
+
+    let content_source_script = format!("{content_script_line_tagged}\n{content_script_body_1}");
+    let prefaces = [
+      "# Test comment\n--cmd sh \"sh -e\"\n  --dest 'a b'\n",
+      "# Test comment\nTest preface\n",
+      "--dest\n",
+      "--list --only 1\n",
+      "--lost\n",
+      "--dest 'a b\n",
+      "# Test comment\n--edit 1 'sh echo changed'\n",
+      "-li\n"
+    ];
+
+    /* setup - add temporary test directory w/ content */
+    test_tree_create(Vec::new());
+
+    /* acquisitions */
+
+    let outputs = prefaces
+      .iter()
+      .map(|preface| {
+        fs::write(&path_source, format!("{preface}{content_source_script}")).unwrap();
+        let output_raw = test_command_get()
+          .args(Vec::from(["run", "--", "-n", &path_source]))
+          .output()
+          .unwrap();
+        (
+          output_raw.status.success(),
+          String::from_utf8_lossy(&output_raw.stdout).to_string(),
+          String::from_utf8_lossy(&output_raw.stderr).to_string()
+        )
+      })
       .collect::<Vec<_>>();
-    let scripts_all_path_1 = format!("{path_dir_scripts}/{content_script_output_filename_1}");
-    let scripts_all_path_2 = format!("{path_dir_scripts}/{content_script_output_filename_2}");
-    let scripts_all_body_1 = fs::read_to_string(&scripts_all_path_1).unwrap();
-    let scripts_all_body_2 = fs::read_to_string(&scripts_all_path_2).unwrap();
 
     test_tree_remove();
 
-    /* assertions - one */
+    /* assertions */
 
-    assert_eq!(output_one.to_string(), format!("{content_script_output_1}"));
+    assert!(outputs[0].0);
+    assert!(outputs[0].1.contains("save to 'a b/"));
 
-    assert_eq!(scripts_one.len(), 1);
-    assert!(scripts_one.contains(&scripts_one_path_1));
-    assert!(content_script_body_1.contains(&scripts_one_body_1));
+    assert!(!outputs[1].0 && outputs[1].2.contains(&format!("{path_source}:2:1: ")) && outputs[1].2.contains("not comment or option"));
+    assert!(!outputs[2].0 && outputs[2].2.contains(&format!("{path_source}:1:1: ")) && outputs[2].2.contains("'--dest' takes DIRNAME"));
+    assert!(!outputs[3].0 && outputs[3].2.contains("one option per line"));
+    assert!(!outputs[4].0 && outputs[4].2.contains("unknown option '--lost'"));
+    assert!(!outputs[5].0 && outputs[5].2.contains("no closing '"));
+    assert!(!outputs[6].0 && outputs[6].2.contains(&format!("{path_source}:2:1: ")) && outputs[6].2.contains("'--edit' for command line only"));
+    assert!(!outputs[7].0 && outputs[7].2.contains("'-i' for command line only"));
+  }
 
-    /* assertions - two */
+  #[test]
+  fn setting_stdin() {
 
-    assert_eq!(output_two.to_string(), format!("{content_script_output_2}"));
+    let [
+      path_dir, _, path_source, path_script, _, _,
+      _, _,
+      _, _, content_source_single, _,
+      _, _, _, _, _,
+      content_script_body, _, _,
+      _, _, _
+    ] = test_values_end_to_end_get();
 
-    assert_eq!(scripts_two.len(), 1);
-    assert!(scripts_two.contains(&scripts_two_path_2));
-    assert!(content_script_body_2.contains(&scripts_two_body_2));
+    let content_source_read = format!("{} sh sh\nread line; echo \"Read $line\"\n", DEFAULTS[3].1);
 
-    /* assertions - all */
+    /* acquisitions */
 
-    assert_eq!(output_all.to_string(), format!("{content_script_output_1}{content_script_output_2}"));
+    let outputs = [
+      (&content_source_read,   "pass",  String::from("input")),
+      (&content_source_single, "paths", path_script.to_owned())
+    ]
+      .map(|(content_source, mode, input)| {
+        test_tree_create(Vec::from([
+          [&path_source, content_source,       "test source"     ],
+          [&path_script, &content_script_body, "test script body"]
+        ]));
+        let mut proc = test_command_get()
+          .args(Vec::from(["run", "--", "-d", &path_dir, "--stdin", mode, &path_source]))
+          .stdin(process::Stdio::piped())
+          .stdout(process::Stdio::piped())
+          .stderr(process::Stdio::piped())
+          .spawn()
+          .unwrap();
+        proc.stdin
+          .take()
+          .unwrap()
+          .write_all(format!("{input}\n").as_bytes())
+          .unwrap();
+        let output_raw = proc
+          .wait_with_output()
+          .unwrap();
+        let source = fs::read_to_string(&path_source).unwrap();
+        test_tree_remove();
+        (String::from_utf8_lossy(&output_raw.stdout).to_string(), source)
+      });
 
-    assert_eq!(scripts_all.len(), 2);
-    assert!(scripts_all.contains(&scripts_all_path_1));
-    assert!(content_script_body_1.contains(&scripts_all_body_1));
-    assert!(scripts_all.contains(&scripts_all_path_2));
-    assert!(content_script_body_2.contains(&scripts_all_body_2));
+    /* assertions */
+
+    assert!(outputs[0].0.contains("Read input"));
+    assert_eq!(content_source_read, outputs[0].1);
+
+    assert!(outputs[1].0.contains(&format!("content of script file '{path_script}'")));
+    assert!(outputs[1].1.contains(&content_script_body));
   }
 
   #[test]
-  fn setting_list() {
+  fn setting_profile() {
 
     let [
       _, _, path_source, _, _, _,
       _, _,
-      _, _, _, content_source_triple,
-      content_script_line_base_1, content_script_line_base_2, _, _, content_script_line_label,
-      _, _, _,
+      _, _, _, _,
+      _, _, content_script_line_tagged, _, _,
+      content_script_body_1, _, _,
       _, _, _
     ] = test_values_end_to_end_get();
 
+    let content_source_preface = "--dest base\n\n[quick]\n--only 1\n--dest scratch\n\n[full]\n--keep 0\n";
+    let content_source = format!("{content_source_preface}{content_script_line_tagged}\n{content_script_body_1}{content_script_line_tagged}\n{content_script_body_1}");
+
     /* setup - add temporary test directory w/ content */
     test_tree_create(Vec::from([
-      [&path_source, &content_source_triple, "test source"]
+      [&path_source, &content_source, "test source"]
     ]));
 
     /* acquisitions */
 
-    let output_raw = process::Command::new("cargo")
-      .args(Vec::from(["run", "--", "-l", &path_source]))
-      .output()
-      .unwrap();
-
-    let output = String::from_utf8_lossy(&output_raw.stdout);
-    let output_lines = output
-      .lines()
+    let outputs = [
+      Vec::from(["-n", &path_source]),
+      Vec::from(["-n", "-P", "quick", &path_source]),
+      Vec::from(["-n", "--profile=full", "-d", "cli", &path_source]),
+      Vec::from(["-n", "-P", "slow", &path_source])
+    ]
+      .into_iter()
+      .map(|args| {
+        let output_raw = test_command_get()
+          .args([Vec::from(["run", "--"]), args].concat())
+          .output()
+          .unwrap();
+        (
+          output_raw.status.success(),
+          String::from_utf8_lossy(&output_raw.stdout).to_string(),
+          String::from_utf8_lossy(&output_raw.stderr).to_string()
+        )
+      })
       .collect::<Vec<_>>();
 
     test_tree_remove();
 
     /* assertions */
 
-    assert!(output_lines[0].contains("1"));
-    assert!(output_lines[0].contains(&content_script_line_base_1));
-
-    assert!(output_lines[1].contains("2"));
-    assert!(output_lines[1].contains(&content_script_line_label));
-    assert!(output_lines[1].contains(&content_script_line_base_2));
-
-    assert!(output_lines[2].contains("3"));
-    assert!(output_lines[2].contains(DEFAULTS[5].1));
+    assert!(outputs[0].1.contains("1: save to 'base/") && outputs[0].1.contains("2: save to 'base/"));
+    assert!(outputs[1].1.contains("1: save to 'scratch/") && !outputs[1].1.contains("2: save"));
+    assert!(outputs[2].1.contains("1: save to 'cli/") && outputs[2].1.contains("2: save to 'cli/"));
+    assert!(!outputs[3].0 && outputs[3].2.contains("Not applying profile 'slow'") && outputs[3].2.contains("quick, full"));
   }
 
   #[test]
-  fn setting_init() {
+  fn setting_cmd() {
 
     let [
       _, _, path_source, _, _, _,
       _, _,
       _, _, _, _,
       _, _, _, _, _,
-      _, _, _,
+      content_script_body_1, content_script_body_2, _,
       _, _, _
     ] = test_values_end_to_end_get();
 
+    let content_source_preface = "--cmd sh 'sh -e'\n\n[alt]\n--cmd txt cat\n";
+    let content_source = format!("{content_source_preface}{tag_head} test.sh\n{content_script_body_1}{tag_head} test.txt\n{content_script_body_2}", tag_head = DEFAULTS[3].1);
+
     /* setup - add temporary test directory w/ content */
-    test_tree_create(Vec::new());
+    test_tree_create(Vec::from([
+      [&path_source, &content_source, "test source"]
+    ]));
 
     /* acquisitions */
 
-    let output_raw = process::Command::new("cargo")
-      .args(Vec::from(["run", "--", "-i", &path_source]))
-      .output()
-      .unwrap();
-
-    let output = String::from_utf8_lossy(&output_raw.stdout);
-    let source = fs::read_to_string(&path_source)
-      .unwrap_or_else(|_| panic!("reading from test source"));
-
-    let defaults = ConfigDefaults::from(DEFAULTS);
-    let settings = settings_new(&defaults);
-    let messages = messages_new(&defaults);
-
-    let config_init = Config {
-      defaults,
-      settings,
-      messages,
-      receipts: HashMap::new()
-    };
+    let outputs = [
+      Vec::from(["-n", "--cmd", "txt", "more", &path_source]),
+      Vec::from(["-n", "-C", "sh", "sh -x", &path_source]),
+      Vec::from(["-n", "-P", "alt", &path_source])
+    ]
+      .into_iter()
+      .map(|args| {
+        let output_raw = test_command_get()
+          .args([Vec::from(["run", "--"]), args].concat())
+          .output()
+          .unwrap();
+        String::from_utf8_lossy(&output_raw.stdout).to_string()
+      })
+      .collect::<Vec<_>>();
 
     test_tree_remove();
 
     /* assertions */
 
-    assert!(output.contains(&path_source));
-    assert!(source.contains(config_init.messages.expect("file")));
-    assert!(source.contains(config_init.messages.expect("line")));
-    assert!(source.contains(config_init.messages.expect("main")));
-    assert!(source.contains(config_init.messages.expect("plus")));
-    assert!(source.contains(config_init.messages.expect("pipe")));
+    assert!(outputs[0].contains("sh -e ") && outputs[0].contains("more "));
+    assert!(outputs[1].contains("sh -x ") && !outputs[1].contains("sh -e "));
+    assert!(outputs[2].contains("sh -e ") && outputs[2].contains("cat "));
   }
 
   #[test]
-  fn setting_push() {
+  fn setting_config() {
 
     let [
-      _, _, path_source, path_script, _, _,
+      _, _, path_source, _, _, _,
       _, _,
-      _, _, content_source_single, _,
-      content_script_line_base_1, _, content_script_line_tagged, _, _,
-      content_script_body, _, _,
+      _, _, _, content_source_triple,
+      _, _, _, _, _,
+      _, _, _,
       _, _, _
     ] = test_values_end_to_end_get();
 
+    let content_source = format!("--dest preface\n--tail ::\n{content_source_triple}");
+
     /* setup - add temporary test directory w/ content */
     test_tree_create(Vec::from([
-      [&path_source, &content_source_single, "test source"     ],
-      [&path_script, &content_script_body,   "test script body"]
+      [&path_source, &content_source, "test source"]
     ]));
 
     /* acquisitions */
 
-    let output_raw = process::Command::new("cargo")
-      .args(Vec::from(["run", "--", "-p", &content_script_line_base_1, &path_script, &path_source]))
+    let output_raw = test_command_get()
+      .args(Vec::from(["run", "--", "--config", "-d", "cli", "-s", "zsh", &path_source]))
       .output()
       .unwrap();
     let output = String::from_utf8_lossy(&output_raw.stdout);
-    let source = fs::read_to_string(&path_source)
-      .unwrap_or_else(|_| panic!("reading from test source"));
-    let source_line = source.lines().nth(4).unwrap();
 
-    let output_tagged_raw = process::Command::new("cargo")
-      .args(Vec::from(["run", "--", "-p", &content_script_line_tagged, &path_script, &path_source]))
+    let output_base_raw = test_command_get()
+      .args(Vec::from(["run", "--", "--config", "-d", "cli", "-B", "source", &path_source]))
       .output()
       .unwrap();
-    let output_tagged = String::from_utf8_lossy(&output_tagged_raw.stdout);
-    let source_tagged = fs::read_to_string(&path_source)
-      .unwrap_or_else(|_| panic!("reading from test source"));
-    let source_tagged_line = source_tagged.lines().nth(4).unwrap();
+    let output_base = String::from_utf8_lossy(&output_base_raw.stdout);
+
+    let line_get = |output: &str, prefix: &str| output
+      .lines()
+      .find(|line| line.trim_start().starts_with(prefix))
+      .unwrap_or_else(|| panic!("find line for '{prefix}'"))
+      .split_whitespace()
+      .collect::<Vec<_>>()
+      .join(" ");
 
     test_tree_remove();
 
     /* assertions */
 
-    assert!(output.contains(&content_script_line_tagged));
-    assert!(output.contains(&path_script));
-    assert!(source.contains(&content_source_single));
-    assert!(source.contains(&content_script_body));
-    assert_eq!(content_script_line_tagged, source_line);
+    assert!(output_raw.status.success());
+    assert_eq!(format!("tag_head {} (compiled default)", DEFAULTS[3].1), line_get(&output, "tag_head"));
+    assert_eq!("--dest 'cli' (command line)", line_get(&output, "--dest"));
+    assert_eq!("--tail '::' (source preface)", line_get(&output, "--tail"));
+    assert_eq!(format!("source path {path_source} (command line)"), line_get(&output, "source path"));
+    assert_eq!("output directory cli (command line)", line_get(&output, "output directory"));
+    assert_eq!("tag tail :: (source preface)", line_get(&output, "tag tail"));
+    assert_eq!(format!("shell zsh {} (command line)", DEFAULTS[9].1), line_get(&output, "shell"));
 
-    assert!(output_tagged.contains(&content_script_line_tagged));
-    assert!(output_tagged.contains(&path_script));
-    assert!(source_tagged.contains(&content_source_single));
-    assert!(source_tagged.contains(&content_script_body));
-    assert_eq!(content_script_line_tagged, source_tagged_line);
+    assert_eq!(format!("output directory {}/cli (command line)", Path::new(&path_source).parent().unwrap().display()), line_get(&output_base, "output directory"));
   }
 
   #[test]
-  fn setting_edit() {
+  fn setting_completions() {
 
     let [
       _, _, path_source, _, _, _,
       _, _,
-      content_source_preface, content_source_script_body, content_source_single, _,
-      content_script_line_base_1, _, content_script_line_tagged, _, _,
+      _, _, _, content_source_triple,
+      content_script_line_base_1, _, _, _, content_script_line_label,
       _, _, _,
       _, _, _
     ] = test_values_end_to_end_get();
 
     /* setup - add temporary test directory w/ content */
     test_tree_create(Vec::from([
-      [&path_source, &content_source_single, "test source"]
-    ]));
-
-    let n_script = "1";
+      [&path_source, &content_source_triple, "test source"]
+    ]));
 
     /* acquisitions */
 
-    let output_raw = process::Command::new("cargo")
-      .args(Vec::from(["run", "--", "-e", &n_script, &content_script_line_base_1, &path_source]))
-      .output()
-      .unwrap();
-    let output = String::from_utf8_lossy(&output_raw.stdout);
-    let source = fs::read_to_string(&path_source)
-      .unwrap_or_else(|_| panic!("reading from test source"));
-    let source_line = source.lines().nth(1).unwrap();
-
-    let output_tagged_raw = process::Command::new("cargo")
-      .args(Vec::from(["run", "--", "-e", &n_script, &content_script_line_tagged, &path_source]))
-      .output()
-      .unwrap();
-    let output_tagged = String::from_utf8_lossy(&output_tagged_raw.stdout);
-    let source_tagged = fs::read_to_string(&path_source)
-      .unwrap_or_else(|_| panic!("reading from test source"));
-    let source_tagged_line = source_tagged.lines().nth(1).unwrap();
+    let outputs = ["bash", "zsh", "fish", "scripts"]
+      .into_iter()
+      .map(|kind| {
+        let output_raw = test_command_get()
+          .args(Vec::from(["run", "--", "--completions", kind, &path_source]))
+          .output()
+          .unwrap();
+        String::from_utf8_lossy(&output_raw.stdout).to_string()
+      })
+      .collect::<Vec<_>>();
 
     test_tree_remove();
 
+    let defaults = ConfigDefaults::from(DEFAULTS, COMMANDS);
+    let settings = settings_new(&defaults);
+
     /* assertions */
 
-    assert!(output.contains(&n_script));
-    assert!(output.contains(&content_script_line_tagged));
-    assert!(source.contains(&content_source_preface));
-    assert!(source.contains(&content_source_script_body));
-    assert_eq!(content_script_line_tagged, source_line);
+    assert!(outputs[0].contains("complete -F _aliesce aliesce"));
+    assert!(outputs[0].contains("--only|-o) kinds=(scripts); break;;"));
+    assert!(outputs[0].contains("--extract|-x) kinds=(scripts file); break;;"));
+    assert!(outputs[1].starts_with("#compdef aliesce"));
+    assert!(outputs[1].contains("'*'{-e,--edit}'[") && outputs[1].contains("]:N:_aliesce_scripts:LINE: '"));
+    assert_eq!(settings.len(), outputs[2].lines().filter(|line| line.starts_with("complete -c aliesce")).count());
+    assert!(settings.iter().all(|setting| setting.strs.len() == setting.kinds.len()));
 
-    assert!(output_tagged.contains(&n_script));
-    assert!(output_tagged.contains(&content_script_line_tagged));
-    assert!(source_tagged.contains(&content_source_preface));
-    assert!(source_tagged.contains(&content_source_script_body));
-    assert_eq!(content_script_line_tagged, source_tagged_line);
+    assert_eq!(format!("1\t{}", content_script_line_base_1.trim()), outputs[3].lines().next().unwrap());
+    assert_eq!(format!("2\t{content_script_line_label}"), outputs[3].lines().nth(1).unwrap());
   }
 
   #[test]
   fn setting_version() {
 
-    let output_raw = process::Command::new("cargo")
+    let output_raw = test_command_get()
       .args(Vec::from(["run", "--", "-v"]))
       .output()
       .unwrap();
@@ -1725,7 +4969,7 @@ mod test {
   #[test]
   fn setting_help() {
 
-    let output_raw = process::Command::new("cargo")
+    let output_raw = test_command_get()
       .args(Vec::from(["run", "--", "-h"]))
       .output()
       .unwrap();
@@ -1741,7 +4985,7 @@ mod test {
       .split("Notes:")
       .collect::<Vec<_>>();
 
-    let defaults = ConfigDefaults::from(DEFAULTS);
+    let defaults = ConfigDefaults::from(DEFAULTS, COMMANDS);
     let settings = settings_new(&defaults);
     let messages = messages_new(&defaults);
 
@@ -1749,7 +4993,9 @@ mod test {
       defaults,
       settings,
       messages,
-      receipts: HashMap::new()
+      receipts: HashMap::new(),
+      text_src: None,
+      dir_base: None
     };
     let messages_notes_line = config_init.messages
       .compose_notes()
@@ -1774,7 +5020,7 @@ mod test {
         setting.char,
         setting.strs.join(" ")
       );
-      assert!(output_usage_line.contains(&arg_set.trim()));
+      assert!(output_usage_line.contains(arg_set.trim()));
     }
 
     /* flags section */
@@ -1806,13 +5052,45 @@ mod test {
     assert_eq!(messages_notes_line, output_notes_line);
   }
 
+  #[test]
+  fn setting_man() {
+
+    let output_raw = test_command_get()
+      .args(Vec::from(["run", "--", "--man"]))
+      .output()
+      .unwrap();
+
+    let output = String::from_utf8_lossy(&output_raw.stdout);
+    let output_unescaped = output.replace("\\-", "-").replace("\\e", "\\");
+
+    let defaults = ConfigDefaults::from(DEFAULTS, COMMANDS);
+    let settings = settings_new(&defaults);
+    let messages = messages_new(&defaults);
+
+    /* assertions */
+
+    let date = output.split('"').nth(1).unwrap_or_default();
+    assert!(output.starts_with(&format!(".TH ALIESCE 1 \"{date}\" \"aliesce v{}\"", env!("CARGO_PKG_VERSION"))));
+    assert!(10 == date.len() && date.chars().enumerate().all(|(i, c)| if 4 == i || 7 == i { '-' == c } else { c.is_ascii_digit() }));
+    for section in ["NAME", "SYNOPSIS", "OPTIONS", "NOTES"] {
+      assert!(output.contains(&format!("\n.SH {section}\n")));
+    }
+    for setting in &settings {
+      let strs = setting.strs.iter().map(|s| format!(" \\fI{s}\\fR")).collect::<String>();
+      assert!(output_unescaped.contains(&format!(".TP\n\\fB-{}\\fR, \\fB--{}\\fR{strs}\n{}\n", setting.char, setting.word, setting.desc)));
+    }
+    for note in messages.compose_notes() {
+      assert!(output_unescaped.contains(&format!(".PP\n{note}")));
+    }
+  }
+
   /*   - unit */
 
   /*     - function: inputs_parse */
 
   fn test_values_inputs_parse_get() -> (Config<'static>, String, usize, String, OutputFilePath, OutputFileInit) {
 
-    let defaults = ConfigDefaults::from(DEFAULTS);
+    let defaults = ConfigDefaults::from(DEFAULTS, COMMANDS);
     let settings = settings_new(&defaults);
     let messages = messages_new(&defaults);
 
@@ -1820,14 +5098,16 @@ mod test {
       defaults,
       settings,
       messages,
-      receipts: HashMap::new()
+      receipts: HashMap::new(),
+      text_src: None,
+      dir_base: None
     };
 
     /* base test script values */
 
     let output_path = OutputFilePath {
       dir:  String::from(config_default.defaults.expect("path_dir")),
-      stem: String::from(config_default.defaults.expect("path_src").split(".").nth(0).unwrap()),
+      stem: String::from(config_default.defaults.expect("path_src").split(".").next().unwrap()),
       ext:  String::from("ext")
     };
 
@@ -1836,10 +5116,10 @@ mod test {
     let number = 1;
     let prog  = String::from("program");
     let args  = Vec::from([String::from("--flag"), String::from("value"), output_path.get()]);
-    let plcs  = Vec::new();
+    let parts = Vec::new();
     let code  = String::from("//code");
 
-    let output_init = OutputFileInit::Code(OutputFileInitCode { prog, args, plcs });
+    let output_init = OutputFileInit::Code(OutputFileInitCode { prog, args, parts });
 
     (config_default, body, number, code, output_path, output_init)
   }
@@ -1858,7 +5138,29 @@ mod test {
     ]);
 
     let expected = Output::File(OutputFile { data, code, path, init, n });
-    let obtained = inputs_parse(&Script { n, line, body }, &config_default);
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
+
+    assert_eq!(expected, obtained);
+  }
+
+  #[test]
+  fn inputs_parse_returns_for_tag_data_full_incl_escape_minus_placeholder_some_output_as_is() {
+
+    let (config_default, body, n, code, path, _) = test_values_inputs_parse_get();
+
+    let line = String::from(" ext program a\\>b\n");
+    let data = Vec::from([
+      String::from("ext"),
+      String::from("program"),
+      String::from("a\\>b")
+    ]);
+
+    let prog = String::from("program");
+    let args = Vec::from([String::from("a\\>b"), path.get()]);
+    let init = OutputFileInit::Code(OutputFileInitCode { prog, args, parts: Vec::new() });
+
+    let expected = Output::File(OutputFile { data, code, path, init, n });
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
 
     assert_eq!(expected, obtained);
   }
@@ -1877,9 +5179,57 @@ mod test {
     ]);
 
     let expected = Output::File(OutputFile { data, code, path, init, n });
-    let obtained = inputs_parse(&Script { n, line, body }, &config_default);
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
+
+    assert_eq!(expected, obtained);
+  }
+
+  #[test]
+  fn inputs_parse_returns_for_tag_label_and_data_full_non_ascii_some_output_file() {
+
+    let (config_default, body, n, code, _, _) = test_values_inputs_parse_get();
+
+    let line = String::from(" Étiquette ☃ # répertoire/scrïpt.ёxt prögram --flâg välue\n");
+    let data = Vec::from([
+      String::from("répertoire/scrïpt.ёxt"),
+      String::from("prögram"),
+      String::from("--flâg"),
+      String::from("välue")
+    ]);
+
+    let dir  = String::from("répertoire");
+    let stem = String::from("scrïpt");
+    let ext  = String::from("ёxt");
+    let path = OutputFilePath { dir, stem, ext };
+
+    let prog  = String::from("prögram");
+    let args  = Vec::from([String::from("--flâg"), String::from("välue"), path.get()]);
+    let parts = Vec::new();
+    let init  = OutputFileInit::Code(OutputFileInitCode { prog, args, parts });
+
+    let expected = Output::File(OutputFile { data, code, path, init, n });
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
+
+    assert_eq!(expected, obtained);
+  }
+
+  #[test]
+  fn inputs_parse_returns_for_tag_head_and_tail_non_ascii_some_output_text() {
+
+    let (mut config_default, body, n, _, _, _) = test_values_inputs_parse_get();
+
+    let line = String::from(" ラベル → ext program --flag value\n");
+
+    config_default.receipts.insert(String::from("list"), ConfigReceiptVal::Bool);
+    config_default.receipts.insert(String::from("head"), ConfigReceiptVal::Strs(Vec::from([String::from("§§")])));
+    config_default.receipts.insert(String::from("tail"), ConfigReceiptVal::Strs(Vec::from([String::from("→")])));
+
+    let expected = Output::Text(OutputText::Stdout(String::from("1: ラベル : ext program --flag value")));
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
 
     assert_eq!(expected, obtained);
+    assert_eq!("§§ ñ sh", tag_head_add("ñ sh", &config_default));
+    assert_eq!("§§ñ sh", tag_head_add("§§ñ sh", &config_default));
   }
 
   #[test]
@@ -1896,15 +5246,15 @@ mod test {
     ]);
 
     let dir  = String::from("dest");
-    let stem = String::from(config_default.defaults.expect("path_src").split(".").nth(0).unwrap());
+    let stem = String::from(config_default.defaults.expect("path_src").split(".").next().unwrap());
     let ext  = String::from("ext");
     let path = OutputFilePath { dir, stem, ext };
 
-    match init { OutputFileInit::Code(ref mut c) => { c.args[2] = path.get() }, _ => () };
+    if let OutputFileInit::Code(ref mut c) = init { c.args[2] = path.get() };
     config_default.receipts.insert(String::from("dest"), ConfigReceiptVal::Strs(Vec::from([String::from("dest")])));
 
     let expected = Output::File(OutputFile { data, code, path, init, n });
-    let obtained = inputs_parse(&Script { n, line, body }, &config_default);
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
 
     assert_eq!(expected, obtained);
   }
@@ -1919,7 +5269,7 @@ mod test {
     config_default.receipts.insert(String::from("list"), ConfigReceiptVal::Bool);
 
     let expected = Output::Text(OutputText::Stdout(String::from("1: ext program --flag value")));
-    let obtained = inputs_parse(&Script { n, line, body }, &config_default);
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
 
     assert_eq!(expected, obtained);
   }
@@ -1942,10 +5292,10 @@ mod test {
     let ext  = String::from("ext");
     let path = OutputFilePath { dir, stem, ext };
 
-    match init { OutputFileInit::Code(ref mut c) => { c.args[2] = path.get() }, _ => () };
+    if let OutputFileInit::Code(ref mut c) = init { c.args[2] = path.get() };
 
     let expected = Output::File(OutputFile { data, code, path, init, n });
-    let obtained = inputs_parse(&Script { n, line, body }, &config_default);
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
 
     assert_eq!(expected, obtained);
   }
@@ -1968,10 +5318,10 @@ mod test {
     let ext  = String::from("ext");
     let path = OutputFilePath { dir, stem, ext };
 
-    match init { OutputFileInit::Code(ref mut c) => { c.args[2] = path.get() }, _ => () };
+    if let OutputFileInit::Code(ref mut c) = init { c.args[2] = path.get() };
 
     let expected = Output::File(OutputFile { data, code, path, init, n });
-    let obtained = inputs_parse(&Script { n, line, body }, &config_default);
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
 
     assert_eq!(expected, obtained);
   }
@@ -1994,10 +5344,10 @@ mod test {
     let ext  = String::from("ext");
     let path = OutputFilePath { dir, stem, ext };
 
-    match init { OutputFileInit::Code(ref mut c) => { c.args[2] = path.get() }, _ => () };
+    if let OutputFileInit::Code(ref mut c) = init { c.args[2] = path.get() };
 
     let expected = Output::File(OutputFile { data, code, path, init, n });
-    let obtained = inputs_parse(&Script { n, line, body }, &config_default);
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
 
     assert_eq!(expected, obtained);
   }
@@ -2020,10 +5370,10 @@ mod test {
     let ext  = String::from("ext");
     let path = OutputFilePath { dir, stem, ext };
 
-    match init { OutputFileInit::Code(ref mut c) => { c.args[2] = path.get() }, _ => () };
+    if let OutputFileInit::Code(ref mut c) = init { c.args[2] = path.get() };
 
     let expected = Output::File(OutputFile { data, code, path, init, n });
-    let obtained = inputs_parse(&Script { n, line, body }, &config_default);
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
 
     assert_eq!(expected, obtained);
   }
@@ -2049,11 +5399,109 @@ mod test {
       String::from(config_default.defaults.expect("cmd_flag")),
       String::from("program_1 --flag value >< | program_2")
     ]);
-    let plcs = Vec::from([(0, String::from("><"))]);
-    let init = OutputFileInit::Code(OutputFileInitCode { prog, args, plcs });
+    let parts = Vec::from([
+      OutputFileCmdPart::Text(String::from("program_1 --flag value ")),
+      OutputFileCmdPart::Path(n, String::from("><")),
+      OutputFileCmdPart::Text(String::from(" | program_2"))
+    ]);
+    let init = OutputFileInit::Code(OutputFileInitCode { prog, args, parts });
+
+    let expected = Output::File(OutputFile { data, code, path, init, n });
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
+
+    assert_eq!(expected, obtained);
+  }
+
+  #[test]
+  fn inputs_parse_returns_for_tag_data_full_incl_shell_prefix_some_output() {
+
+    let (config_default, body, n, code, path, _) = test_values_inputs_parse_get();
+
+    let line = String::from(" @sh,-ec ext program_1 >< | program_2\n");
+    let data = Vec::from([
+      String::from("ext"),
+      String::from("program_1"),
+      String::from("><"),
+      String::from("|"),
+      String::from("program_2")
+    ]);
+
+    let prog = String::from("sh");
+    let args = Vec::from([String::from("-ec"), String::from("program_1 >< | program_2")]);
+    let parts = Vec::from([
+      OutputFileCmdPart::Text(String::from("program_1 ")),
+      OutputFileCmdPart::Path(n, String::from("><")),
+      OutputFileCmdPart::Text(String::from(" | program_2"))
+    ]);
+    let init = OutputFileInit::Code(OutputFileInitCode { prog, args, parts });
+
+    let expected = Output::File(OutputFile { data, code, path, init, n });
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
+
+    assert_eq!(expected, obtained);
+  }
+
+  #[test]
+  fn inputs_parse_returns_for_tag_data_full_incl_shell_prefix_with_shell_sig_option_some_output() {
+
+    let (mut config_default, body, n, code, path, _) = test_values_inputs_parse_get();
+
+    let line = String::from(" %sh,-ec ext program_1 >< | program_2\n");
+    let data = Vec::from([
+      String::from("ext"),
+      String::from("program_1"),
+      String::from("><"),
+      String::from("|"),
+      String::from("program_2")
+    ]);
+
+    let prog = String::from("sh");
+    let args = Vec::from([String::from("-ec"), String::from("program_1 >< | program_2")]);
+    let parts = Vec::from([
+      OutputFileCmdPart::Text(String::from("program_1 ")),
+      OutputFileCmdPart::Path(n, String::from("><")),
+      OutputFileCmdPart::Text(String::from(" | program_2"))
+    ]);
+    let init = OutputFileInit::Code(OutputFileInitCode { prog, args, parts });
+
+    config_default.receipts.insert(String::from("shell-sig"), ConfigReceiptVal::Strs(Vec::from([String::from("%")])));
+
+    let expected = Output::File(OutputFile { data, code, path, init, n });
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
+
+    assert_eq!(expected, obtained);
+  }
+
+  #[test]
+  fn inputs_parse_returns_for_tag_data_full_incl_shell_option_some_output() {
+
+    let (mut config_default, body, n, code, path, _) = test_values_inputs_parse_get();
+
+    let line = String::from(" ext program_1 >< | program_2\n");
+    let data = Vec::from([
+      String::from("ext"),
+      String::from("program_1"),
+      String::from("><"),
+      String::from("|"),
+      String::from("program_2")
+    ]);
+
+    let prog = String::from("zsh");
+    let args = Vec::from([
+      String::from(config_default.defaults.expect("cmd_flag")),
+      String::from("program_1 >< | program_2")
+    ]);
+    let parts = Vec::from([
+      OutputFileCmdPart::Text(String::from("program_1 ")),
+      OutputFileCmdPart::Path(n, String::from("><")),
+      OutputFileCmdPart::Text(String::from(" | program_2"))
+    ]);
+    let init = OutputFileInit::Code(OutputFileInitCode { prog, args, parts });
+
+    config_default.receipts.insert(String::from("shell"), ConfigReceiptVal::Strs(Vec::from([String::from("zsh")])));
 
     let expected = Output::File(OutputFile { data, code, path, init, n });
-    let obtained = inputs_parse(&Script { n, line, body }, &config_default);
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
 
     assert_eq!(expected, obtained);
   }
@@ -2069,7 +5517,81 @@ mod test {
     let init = OutputFileInit::Text(OutputText::Stderr(String::from("Not running file no. 1 (no values)")));
 
     let expected = Output::File(OutputFile { data, code, path, init, n });
-    let obtained = inputs_parse(&Script { n, line, body }, &config_default);
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
+
+    assert_eq!(expected, obtained);
+  }
+
+  #[test]
+  fn inputs_parse_returns_for_tag_data_minus_cmd_incl_ext_with_cmd_some_output_file() {
+
+    let (config_default, body, n, code, _, _) = test_values_inputs_parse_get();
+
+    let line = String::from(" py\n");
+    let data = Vec::from([String::from("py")]);
+
+    let dir  = String::from(config_default.defaults.expect("path_dir"));
+    let stem = String::from(config_default.defaults.expect("path_src").split(".").next().unwrap());
+    let ext  = String::from("py");
+    let path = OutputFilePath { dir, stem, ext };
+
+    let prog = String::from(config_default.defaults.commands.get("py").unwrap());
+    let args = Vec::from([path.get()]);
+    let parts = Vec::new();
+    let init = OutputFileInit::Code(OutputFileInitCode { prog, args, parts });
+
+    let expected = Output::File(OutputFile { data, code, path, init, n });
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
+
+    assert_eq!(expected, obtained);
+  }
+
+  #[test]
+  fn inputs_parse_returns_for_tag_data_minus_cmd_incl_cmd_option_some_output_file() {
+
+    let (mut config_default, body, n, code, _, _) = test_values_inputs_parse_get();
+
+    let line = String::from(" py\n");
+    let data = Vec::from([String::from("py")]);
+
+    let dir  = String::from(config_default.defaults.expect("path_dir"));
+    let stem = String::from(config_default.defaults.expect("path_src").split(".").next().unwrap());
+    let ext  = String::from("py");
+    let path = OutputFilePath { dir, stem, ext };
+
+    let prog = String::from("python3");
+    let args = Vec::from([String::from("-X"), String::from("dev"), path.get()]);
+    let parts = Vec::new();
+    let init = OutputFileInit::Code(OutputFileInitCode { prog, args, parts });
+
+    config_default.receipts.insert(String::from("cmd"), ConfigReceiptVal::Strs(Vec::from([
+      String::from("py"), String::from("python2"),
+      String::from("py"), String::from("python3 -X dev")
+    ])));
+
+    let expected = Output::File(OutputFile { data, code, path, init, n });
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
+
+    assert_eq!(expected, obtained);
+  }
+
+  #[test]
+  fn inputs_parse_returns_for_tag_data_incl_ext_with_cmd_and_bypass_some_output_file_indicating() {
+
+    let (config_default, body, n, code, _, _) = test_values_inputs_parse_get();
+
+    let line = String::from(" py !\n");
+    let data = Vec::from([String::from("py"), String::from("!")]);
+
+    let dir  = String::from(config_default.defaults.expect("path_dir"));
+    let stem = String::from(config_default.defaults.expect("path_src").split(".").next().unwrap());
+    let ext  = String::from("py");
+    let path = OutputFilePath { dir, stem, ext };
+
+    let init = OutputFileInit::Text(OutputText::Stderr(String::from("Not running file no. 1 (! applied)")));
+
+    let expected = Output::File(OutputFile { data, code, path, init, n });
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
 
     assert_eq!(expected, obtained);
   }
@@ -2082,7 +5604,7 @@ mod test {
     let line = String::from(" ! ext program --flag value\n");
 
     let expected = Output::Text(OutputText::Stderr(String::from("Bypassing script no. 1 (! applied)")));
-    let obtained = inputs_parse(&Script { n, line, body }, &config_default);
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
 
     assert_eq!(expected, obtained);
   }
@@ -2094,8 +5616,136 @@ mod test {
 
     let line = String::from("\n");
 
-    let expected = Output::Text(OutputText::Stderr(String::from("No tag data found for script no. 1")));
-    let obtained = inputs_parse(&Script { n, line, body }, &config_default);
+    let expected = Output::Text(OutputText::Stderr(format!(
+      "{}:1:4: No tag data found for script no. 1\n  {}\n     ^",
+      DEFAULTS[0].1,
+      DEFAULTS[3].1
+    )));
+    let obtained = inputs_parse(&Script { n, line_n: 1, line, body }, &config_default);
+
+    assert_eq!(expected, obtained);
+  }
+
+  /*     - function: OutputManifest::hash_get */
+
+  #[test]
+  fn output_manifest_hash_get_returns_for_bytes_fnv_1a_hash() {
+
+    let bytes: [&[u8]; 3] = [b"", b"a", b"foobar"];
+
+    let expected = [0xcbf29ce484222325, 0xaf63dc4c8601ec8c, 0x85944171f73967e8];
+    let obtained = bytes.map(OutputManifest::hash_get);
+
+    assert_eq!(expected, obtained);
+  }
+
+  /*     - function: OutputFilePath::stem_from_path_get */
+
+  #[test]
+  fn output_file_path_stem_from_path_get_returns_for_paths_filename_stem() {
+
+    let paths = ["src.txt", "./proj/src.txt", "../x.y/src", "dir/.aliesce.txt"];
+
+    let expected = ["src", "src", "src", "aliesce"].map(String::from);
+    let obtained = paths.map(OutputFilePath::stem_from_path_get);
+
+    assert_eq!(expected, obtained);
+  }
+
+  /*     - function: OutputFileCmdPart::parse */
+
+  #[test]
+  fn output_file_cmd_part_parse_returns_for_item_with_placeholders_multiple_parts() {
+
+    let item = ">><>200<a>1<";
+
+    let expected = Vec::from([
+      OutputFileCmdPart::Text(String::from(">")),
+      OutputFileCmdPart::Path(5, String::from("><")),
+      OutputFileCmdPart::Path(200, String::from(">200<")),
+      OutputFileCmdPart::Text(String::from("a")),
+      OutputFileCmdPart::Path(1, String::from(">1<"))
+    ]);
+    let obtained = OutputFileCmdPart::parse(item, ">", "<", 5);
+
+    assert_eq!(expected, obtained);
+  }
+
+  #[test]
+  fn output_file_cmd_part_parse_returns_for_item_with_placeholders_escaped_or_invalid_text_part() {
+
+    let item = r"\>1\<a>b<2>/dev/null\x";
+
+    let expected = Vec::from([
+      OutputFileCmdPart::Text(String::from(r">1<a>b<2>/dev/null\x"))
+    ]);
+    let obtained = OutputFileCmdPart::parse(item, ">", "<", 5);
+
+    assert_eq!(expected, obtained);
+  }
+
+  /*     - function: stdin_paths_get */
+
+  #[test]
+  fn stdin_paths_get_returns_for_bytes_nul_delimited_paths_as_is() {
+
+    let bytes = b"a b.txt\0dir/c\xff.txt\0\0";
+
+    let expected = Vec::from([PathBuf::from("a b.txt"), path_from_bytes(b"dir/c\xff.txt")]);
+    let obtained = stdin_paths_get(bytes);
+
+    assert_eq!(expected, obtained);
+  }
+
+  #[test]
+  fn stdin_paths_get_returns_for_bytes_line_delimited_without_files_parts() {
+
+    let bytes = b"a b.txt\r\n\ndir/c.txt \n";
+
+    let expected = Vec::from([PathBuf::from("a"), PathBuf::from("b.txt"), PathBuf::from("dir/c.txt")]);
+    let obtained = stdin_paths_get(bytes);
+
+    assert_eq!(expected, obtained);
+  }
+
+  /*     - function: preface_line_split */
+
+  #[test]
+  fn preface_line_split_returns_for_line_quoted_and_escaped_ok_args() {
+
+    let line = r#"--cmd  py 'python3 -X "dev"' "a \"b\" \c" d\ e ''"#;
+
+    let expected = Ok(Vec::from([
+      String::from("--cmd"),
+      String::from("py"),
+      String::from("python3 -X \"dev\""),
+      String::from("a \"b\" \\c"),
+      String::from("d e"),
+      String::new()
+    ]));
+    let obtained = preface_line_split(line);
+
+    assert_eq!(expected, obtained);
+  }
+
+  #[test]
+  fn preface_line_split_returns_for_line_with_quote_unclosed_err() {
+
+    let line = "--dest \"a b";
+
+    let expected = Err(String::from("no closing \""));
+    let obtained = preface_line_split(line);
+
+    assert_eq!(expected, obtained);
+  }
+
+  /*     - function: date_from_days_get */
+
+  #[test]
+  fn date_from_days_get_returns_for_days_incl_leap_day_date() {
+
+    let expected = [String::from("1970-01-01"), String::from("2000-02-29"), String::from("2024-12-31")];
+    let obtained = [0, 11016, 20088].map(date_from_days_get);
 
     assert_eq!(expected, obtained);
   }